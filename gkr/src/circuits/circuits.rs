@@ -3,7 +3,8 @@ use std::marker::PhantomData;
 use crate::circuits::gates::{Gate, GateOperation};
 use crate::circuits::layers::Layer;
 use ark_ff::PrimeField;
-use multilinear::evaluation_form::{combine_convert, MultilinearEvalForm, Op, ProdPoly, SumPoly};
+use multilinear::evaluation_form::{MultilinearEvalForm, Op, ProdPoly, SumPoly};
+use multilinear::hypercube::combine_convert;
 
 pub struct Circuit<F: PrimeField> {
     pub layers: Vec<Layer>,
@@ -39,8 +40,9 @@ impl<F: PrimeField> Circuit<F> {
         if length <= layer_index || length == 0 {
             panic!("Compute circuit first!");
         }
+        // layers whose width is not a power of two are padded with zeros
         let layer_vec = &layer_evaluations[layer_index as usize];
-        MultilinearEvalForm::new(layer_vec.to_vec())
+        MultilinearEvalForm::from_padded(layer_vec.to_vec(), F::zero())
     }
 
     pub fn add_and_mul_i(
@@ -63,26 +65,13 @@ impl<F: PrimeField> Circuit<F> {
         let mut add_eval_form: Vec<F> = vec![F::zero(); 2u32.pow(val) as usize];
         let mut mul_eval_form: Vec<F> = vec![F::zero(); 2u32.pow(val) as usize];
 
+        // every gate sets the point (output, left, right) of its wiring polynomial
+        let width = to_log2(no_of_gates * 2);
         for gate in &layer.gates {
+            let index = combine_convert(&[gate.output, gate.left_index, gate.right_index], width);
             if gate.op == GateOperation::Add {
-                let index = combine_convert(
-                    vec![
-                        gate.output as u32,
-                        gate.left_index as u32,
-                        gate.right_index as u32,
-                    ],
-                    to_log2(no_of_gates * 2) as usize,
-                ) as usize;
                 add_eval_form[index] = F::one();
             } else if gate.op == GateOperation::Mul {
-                let index = combine_convert(
-                    vec![
-                        gate.output as u32,
-                        gate.left_index as u32,
-                        gate.right_index as u32,
-                    ],
-                    to_log2(no_of_gates * 2) as usize,
-                ) as usize;
                 mul_eval_form[index] = F::one();
             }
         }
@@ -256,11 +245,20 @@ mod test {
         );
 
         assert_eq!(
-            Circuit::w_mle(1, &layer_evaluations).evaluate(&vec![Fq::from(0)]),
+            Circuit::w_mle(1, &layer_evaluations).evaluate(&[Fq::from(0)]),
             Fq::from(21)
         );
     }
 
+    #[test]
+    fn test_w_mle_of_odd_width() {
+        let layer_evaluations = vec![vec![Fq::from(5), Fq::from(7), Fq::from(9)]];
+        assert_eq!(
+            Circuit::w_mle(0, &layer_evaluations).eval_form,
+            vec![Fq::from(5), Fq::from(7), Fq::from(9), Fq::from(0)]
+        );
+    }
+
     #[test]
     fn test_add_and_mul_i() {
         let circuit_example: Circuit<Fq> = get_circuit3();
@@ -268,45 +266,31 @@ mod test {
         // let inputs:Vec<u32> = vec![0, 0, 1];
         let (add_i_poly, mul_i_poly) = circuit_example.add_and_mul_i(layer_index);
         assert_eq!(
-            add_i_poly
-                .clone()
-                .evaluate(&convert_to_fq_elements(vec![0, 0, 0, 0, 0, 0, 0, 1])),
+            add_i_poly.evaluate(&convert_to_fq_elements(vec![0, 0, 0, 0, 0, 0, 0, 1])),
             Fq::from(1)
         );
         assert_eq!(
-            add_i_poly
-                .clone()
-                .evaluate(&convert_to_fq_elements(vec![0, 1, 0, 1, 0, 0, 1, 1])),
+            add_i_poly.evaluate(&convert_to_fq_elements(vec![0, 1, 0, 1, 0, 0, 1, 1])),
             Fq::from(1)
         );
         assert_eq!(
-            add_i_poly
-                .clone()
-                .evaluate(&convert_to_fq_elements(vec![1, 0, 1, 0, 0, 1, 0, 1])),
+            add_i_poly.evaluate(&convert_to_fq_elements(vec![1, 0, 1, 0, 0, 1, 0, 1])),
             Fq::from(1)
         );
         assert_eq!(
-            add_i_poly
-                .clone()
-                .evaluate(&convert_to_fq_elements(vec![1, 1, 0, 1, 1, 0, 0, 1])),
+            add_i_poly.evaluate(&convert_to_fq_elements(vec![1, 1, 0, 1, 1, 0, 0, 1])),
             Fq::from(0)
         );
         assert_eq!(
-            mul_i_poly
-                .clone()
-                .evaluate(&convert_to_fq_elements(vec![1, 1, 1, 1, 0, 1, 1, 1])),
+            mul_i_poly.evaluate(&convert_to_fq_elements(vec![1, 1, 1, 1, 0, 1, 1, 1])),
             Fq::from(1)
         );
         assert_eq!(
-            mul_i_poly
-                .clone()
-                .evaluate(&convert_to_fq_elements(vec![0, 1, 1, 1, 0, 1, 1, 1])),
+            mul_i_poly.evaluate(&convert_to_fq_elements(vec![0, 1, 1, 1, 0, 1, 1, 1])),
             Fq::from(0)
         );
         assert_eq!(
-            mul_i_poly
-                .clone()
-                .evaluate(&convert_to_fq_elements(vec![1, 1, 1, 1, 1, 1, 1, 1])),
+            mul_i_poly.evaluate(&convert_to_fq_elements(vec![1, 1, 1, 1, 1, 1, 1, 1])),
             Fq::from(0)
         );
     }
@@ -317,27 +301,19 @@ mod test {
         // let inputs:Vec<u32> = vec![0, 0, 1];
         let (add_i_poly, mul_i_poly) = circuit_example.add_and_mul_i(layer_index);
         assert_eq!(
-            add_i_poly
-                .clone()
-                .evaluate(&convert_to_fq_elements(vec![0, 0, 0, 0, 1])),
+            add_i_poly.evaluate(&convert_to_fq_elements(vec![0, 0, 0, 0, 1])),
             Fq::from(0)
         );
         assert_eq!(
-            add_i_poly
-                .clone()
-                .evaluate(&convert_to_fq_elements(vec![1, 1, 0, 1, 1])),
+            add_i_poly.evaluate(&convert_to_fq_elements(vec![1, 1, 0, 1, 1])),
             Fq::from(1)
         );
         assert_eq!(
-            mul_i_poly
-                .clone()
-                .evaluate(&convert_to_fq_elements(vec![1, 1, 1, 0, 1])),
+            mul_i_poly.evaluate(&convert_to_fq_elements(vec![1, 1, 1, 0, 1])),
             Fq::from(0)
         );
         assert_eq!(
-            mul_i_poly
-                .clone()
-                .evaluate(&convert_to_fq_elements(vec![0, 0, 0, 0, 1])),
+            mul_i_poly.evaluate(&convert_to_fq_elements(vec![0, 0, 0, 0, 1])),
             Fq::from(1)
         );
     }
@@ -348,27 +324,19 @@ mod test {
         // let inputs:Vec<u32> = vec![0, 0, 1];
         let (add_i_poly, mul_i_poly) = circuit_example.add_and_mul_i(layer_index);
         assert_eq!(
-            add_i_poly
-                .clone()
-                .evaluate(&convert_to_fq_elements(vec![0, 0, 0])),
+            add_i_poly.evaluate(&convert_to_fq_elements(vec![0, 0, 0])),
             Fq::from(0)
         );
         assert_eq!(
-            add_i_poly
-                .clone()
-                .evaluate(&convert_to_fq_elements(vec![0, 0, 1])),
+            add_i_poly.evaluate(&convert_to_fq_elements(vec![0, 0, 1])),
             Fq::from(1)
         );
         assert_eq!(
-            mul_i_poly
-                .clone()
-                .evaluate(&convert_to_fq_elements(vec![1, 1, 1])),
+            mul_i_poly.evaluate(&convert_to_fq_elements(vec![1, 1, 1])),
             Fq::from(0)
         );
         assert_eq!(
-            mul_i_poly
-                .clone()
-                .evaluate(&convert_to_fq_elements(vec![0, 0, 1])),
+            mul_i_poly.evaluate(&convert_to_fq_elements(vec![0, 0, 1])),
             Fq::from(0)
         );
     }
@@ -1,7 +1,9 @@
 use ark_bn254::Fq;
 use criterion::{black_box, criterion_group, criterion_main, Criterion};
+use multilinear::coefficient_form::dense_multilinear::DenseMultilinear;
+use multilinear::evaluation_form::eq_polynomial::{build_eq_table, EqPolynomial};
 use multilinear::evaluation_form::{
-    find_pairs_with_xor, gen_based_on_two, gen_random_vars, MultilinearEvalForm,
+    gen_based_on_two, gen_random_vars, MultilinearEvalForm, ProdPoly, SumPoly,
 };
 
 fn benchmark(c: &mut Criterion) -> () {
@@ -26,35 +28,6 @@ fn benchmark(c: &mut Criterion) -> () {
 
     let a_target = 2;
 
-    // find pairs
-    group.bench_function("find_pairs_with_xor", |b| {
-        b.iter(|| black_box(find_pairs_with_xor(&poly.boolean_hypercube, a_target)))
-    });
-    group.bench_function("find_pairs_with_xor_3vars", |b| {
-        b.iter(|| {
-            black_box(find_pairs_with_xor(
-                &poly_of_3vars.boolean_hypercube,
-                a_target,
-            ))
-        })
-    });
-    group.bench_function("find_pairs_with_xor_10vars", |b| {
-        b.iter(|| {
-            black_box(find_pairs_with_xor(
-                &poly_of_10vars.boolean_hypercube,
-                a_target,
-            ))
-        })
-    });
-    group.bench_function("find_pairs_with_xor_20vars", |b| {
-        b.iter(|| {
-            black_box(find_pairs_with_xor(
-                &poly_of_20vars.boolean_hypercube,
-                a_target,
-            ))
-        })
-    });
-
     // partial evaluate
     group.bench_function("partial_evaluate", |b| {
         b.iter(|| black_box(poly.clone().partial_evaluate(a_target, Fq::from(2))));
@@ -87,24 +60,65 @@ fn benchmark(c: &mut Criterion) -> () {
         });
     });
 
+    // fixing the first variable halves the table, the sumcheck prover does this every round
+    group.bench_function("partial_evaluate_first_var_20vars", |b| {
+        b.iter(|| black_box(poly_of_20vars.clone().partial_evaluate(1, Fq::from(2))));
+    });
+    group.bench_function("partial_evaluate_last_var_20vars", |b| {
+        b.iter(|| black_box(poly_of_20vars.clone().partial_evaluate(20, Fq::from(2))));
+    });
+
     // evaluate
     group.bench_function("evaluate_for_2vars", |b| {
-        b.iter(|| black_box(poly.clone().evaluate(&vec![Fq::from(2), Fq::from(3)])))
+        b.iter(|| black_box(poly.evaluate(&[Fq::from(2), Fq::from(3)])))
     });
     group.bench_function("evaluate_for_3vars", |b| {
+        b.iter(|| black_box(poly_of_3vars.evaluate(&[Fq::from(4), Fq::from(2), Fq::from(3)])))
+    });
+    group.bench_function("evaluate_for_10vars", |b| {
+        b.iter(|| black_box(poly_of_10vars.evaluate(&gen_random_vars(10))))
+    });
+    group.bench_function("evaluate_for_20vars", |b| {
+        b.iter(|| black_box(poly_of_20vars.evaluate(&gen_random_vars(20))))
+    });
+
+    // sum of products, compare against `cargo bench --features parallel`
+    let prod_poly_of_20vars = ProdPoly::new(vec![poly_of_20vars.clone(), poly_of_20vars.clone()]);
+    let sum_poly_of_20vars = SumPoly::new(vec![prod_poly_of_20vars.clone(), prod_poly_of_20vars]);
+    group.bench_function("sum_poly_reduce_20vars", |b| {
+        b.iter(|| black_box(sum_poly_of_20vars.reduce()))
+    });
+
+    // Möbius and zeta transforms between the evaluation and coefficient forms
+    group.bench_function("from_eval_form_20vars", |b| {
+        b.iter(|| black_box(DenseMultilinear::from_eval_form(&poly_of_20vars)))
+    });
+    let dense_of_20vars = DenseMultilinear::from_eval_form(&poly_of_20vars);
+    group.bench_function("to_eval_form_20vars", |b| {
+        b.iter(|| black_box(dense_of_20vars.to_eval_form()))
+    });
+
+    // eq(x, r) over the hypercube, the full table against the split tables
+    let r_of_20vars = gen_random_vars::<Fq>(20);
+    group.bench_function("build_eq_table_20vars", |b| {
+        b.iter(|| black_box(build_eq_table(&r_of_20vars)))
+    });
+    group.bench_function("eq_inner_product_20vars", |b| {
         b.iter(|| {
             black_box(
-                poly_of_3vars
-                    .clone()
-                    .evaluate(&vec![Fq::from(4), Fq::from(2), Fq::from(3)]),
+                EqPolynomial::new(r_of_20vars.clone()).inner_product(&poly_of_20vars.eval_form),
             )
         })
     });
-    group.bench_function("evaluate_for_10vars", |b| {
-        b.iter(|| black_box(poly_of_10vars.clone().evaluate(&gen_random_vars(10))))
-    });
-    group.bench_function("evaluate_for_20vars", |b| {
-        b.iter(|| black_box(poly_of_20vars.clone().evaluate(&gen_random_vars(20))))
+
+    // W(b) * W(c) for a layer of 2^10 gates
+    group.bench_function("tensor_mul_10vars_by_10vars", |b| {
+        b.iter(|| {
+            black_box(MultilinearEvalForm::tensor_mul(
+                &poly_of_10vars,
+                &poly_of_10vars,
+            ))
+        })
     });
     group.finish();
 }
@@ -1,10 +1,270 @@
-#[cfg(test)]
+use crate::evaluation_form::{get_power_of_two, MultilinearEvalForm};
+use crate::parallel::cfg_chunks_mut;
+use ark_ff::PrimeField;
+#[cfg(feature = "parallel")]
+use rayon::prelude::*;
+use std::ops::{Add, Neg, Sub};
+
+// ==============================================================//
+//    @note Dense Multilinear Poly (monomial basis)
+// =============================================================//
+
+// for f(a,b,c) the coefficient at index 0b101 belongs to the monomial ac
+// the first variable is the most significant bit, the same order as MultilinearEvalForm
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct DenseMultilinear<F: PrimeField> {
+    pub number_of_variables: u32,
+    pub coefficients: Vec<F>,
+}
+
+impl<F: PrimeField> DenseMultilinear<F> {
+    pub fn new(coefficients: Vec<F>) -> Self {
+        let number_of_variables = get_power_of_two(coefficients.len() as u32);
+        Self {
+            number_of_variables,
+            coefficients,
+        }
+    }
+
+    // the zero polynomial over number_of_variables variables
+    pub fn zero(number_of_variables: u32) -> Self {
+        Self::new(vec![F::zero(); 1 << number_of_variables])
+    }
+
+    // Möbius transform of the evaluations over the boolean hypercube
+    pub fn from_eval_form(polynomial: &MultilinearEvalForm<F>) -> Self {
+        let mut coefficients = polynomial.eval_form.clone();
+        mobius_transform(&mut coefficients);
+        Self::new(coefficients)
+    }
+
+    // zeta transform back to the evaluations over the boolean hypercube
+    pub fn to_eval_form(&self) -> MultilinearEvalForm<F> {
+        let mut eval_form = self.coefficients.clone();
+        zeta_transform(&mut eval_form);
+        MultilinearEvalForm::new(eval_form)
+    }
+
+    // the coefficient of the monomial made of the given variables (0-based), e.g [0, 2] -> ac
+    pub fn coefficient(&self, variables: &[usize]) -> F {
+        let n = self.number_of_variables as usize;
+        let monomial = variables.iter().fold(0, |monomial, i| {
+            if *i >= n {
+                panic!("variable {} does not exist", i);
+            }
+            monomial | (1 << (n - 1 - i))
+        });
+        self.coefficients[monomial]
+    }
+
+    // the order of the variables is important -> [a, b, c, d,...] for f(a,b,c,d,...)
+    pub fn evaluate(&self, variables: &[F]) -> F {
+        if variables.len() != self.number_of_variables as usize {
+            panic!("Invalid number of points")
+        }
+        // f = f_0(b, c) + a * f_1(b, c), so every variable halves the coefficients
+        let mut coefficients = self.coefficients.clone();
+        for value in variables {
+            let half = coefficients.len() / 2;
+            let (low, high) = coefficients.split_at_mut(half);
+            for (c_0, c_1) in low.iter_mut().zip(high.iter()) {
+                *c_0 += *value * c_1;
+            }
+            coefficients.truncate(half);
+        }
+        coefficients[0]
+    }
+
+    pub fn scalar_mul(&self, scalar: F) -> Self {
+        Self::new(self.coefficients.iter().map(|c| *c * scalar).collect())
+    }
+
+    // the product of two polynomials over disjoint variables, self's variables come first
+    // e.g (a + 1) and (2b) -> 2ab + 2b
+    pub fn tensor_mul(&self, other: &DenseMultilinear<F>) -> Self {
+        let coefficients = self
+            .coefficients
+            .iter()
+            .flat_map(|c| other.coefficients.iter().map(move |d| *c * d))
+            .collect();
+        Self::new(coefficients)
+    }
+}
+
+impl<F: PrimeField> Add for &DenseMultilinear<F> {
+    type Output = DenseMultilinear<F>;
+
+    fn add(self, other: &DenseMultilinear<F>) -> DenseMultilinear<F> {
+        if self.number_of_variables != other.number_of_variables {
+            panic!("Polynomials must be of the same number of variables");
+        }
+        DenseMultilinear::new(
+            self.coefficients
+                .iter()
+                .zip(other.coefficients.iter())
+                .map(|(a, b)| *a + b)
+                .collect(),
+        )
+    }
+}
+
+impl<F: PrimeField> Sub for &DenseMultilinear<F> {
+    type Output = DenseMultilinear<F>;
+
+    fn sub(self, other: &DenseMultilinear<F>) -> DenseMultilinear<F> {
+        if self.number_of_variables != other.number_of_variables {
+            panic!("Polynomials must be of the same number of variables");
+        }
+        DenseMultilinear::new(
+            self.coefficients
+                .iter()
+                .zip(other.coefficients.iter())
+                .map(|(a, b)| *a - b)
+                .collect(),
+        )
+    }
+}
+
+impl<F: PrimeField> Neg for &DenseMultilinear<F> {
+    type Output = DenseMultilinear<F>;
+
+    fn neg(self) -> DenseMultilinear<F> {
+        DenseMultilinear::new(self.coefficients.iter().map(|c| -*c).collect())
+    }
+}
+
+impl<F: PrimeField> Add for DenseMultilinear<F> {
+    type Output = DenseMultilinear<F>;
 
+    fn add(self, other: DenseMultilinear<F>) -> DenseMultilinear<F> {
+        &self + &other
+    }
+}
+
+impl<F: PrimeField> Sub for DenseMultilinear<F> {
+    type Output = DenseMultilinear<F>;
+
+    fn sub(self, other: DenseMultilinear<F>) -> DenseMultilinear<F> {
+        &self - &other
+    }
+}
+
+impl<F: PrimeField> Neg for DenseMultilinear<F> {
+    type Output = DenseMultilinear<F>;
+
+    fn neg(self) -> DenseMultilinear<F> {
+        -&self
+    }
+}
+
+// both transforms walk the variables one at a time, pairing every index that has the variable's
+// bit clear (low) with the index that has it set (high), so each costs n * 2^(n-1) additions
+
+// evaluations over the hypercube -> monomial coefficients, in place
+pub fn mobius_transform<F: PrimeField>(values: &mut [F]) {
+    let mut half = 1;
+    while half < values.len() {
+        cfg_chunks_mut!(values, 2 * half).for_each(|block| {
+            let (low, high) = block.split_at_mut(half);
+            for (y_0, y_1) in low.iter().zip(high.iter_mut()) {
+                *y_1 -= y_0;
+            }
+        });
+        half *= 2;
+    }
+}
+
+// monomial coefficients -> evaluations over the hypercube, in place
+pub fn zeta_transform<F: PrimeField>(values: &mut [F]) {
+    let mut half = 1;
+    while half < values.len() {
+        cfg_chunks_mut!(values, 2 * half).for_each(|block| {
+            let (low, high) = block.split_at_mut(half);
+            for (c_0, c_1) in low.iter().zip(high.iter_mut()) {
+                *c_1 += c_0;
+            }
+        });
+        half *= 2;
+    }
+}
+
+#[cfg(test)]
 mod tests {
     use super::*;
+    use crate::evaluation_form::{convert_to_fq_elements, gen_based_on_two, gen_random_vars};
+    use ark_bn254::Fq;
+
+    #[test]
+    fn test_from_eval_form() {
+        // 3ab + 2a + 1 takes 1, 1, 3, 6 on 00, 01, 10, 11
+        let eval_form = MultilinearEvalForm::new(convert_to_fq_elements(vec![1, 1, 3, 6]));
+        let dense = DenseMultilinear::from_eval_form(&eval_form);
+        assert_eq!(dense.coefficients, convert_to_fq_elements(vec![1, 0, 2, 3]));
+        assert_eq!(dense.coefficient(&[0, 1]), Fq::from(3));
+        assert_eq!(dense.coefficient(&[0]), Fq::from(2));
+        assert_eq!(dense.coefficient(&[1]), Fq::from(0));
+        assert_eq!(dense.coefficient(&[]), Fq::from(1));
+        assert_eq!(dense.to_eval_form().eval_form, eval_form.eval_form);
+    }
+
+    #[test]
+    fn test_transforms_round_trip() {
+        let values = gen_based_on_two::<Fq>(6);
+        let mut transformed = values.clone();
+        mobius_transform(&mut transformed);
+        zeta_transform(&mut transformed);
+        assert_eq!(transformed, values);
+    }
+
+    #[test]
+    fn test_evaluate_matches_eval_form() {
+        let eval_form = MultilinearEvalForm::new(gen_based_on_two::<Fq>(5));
+        let dense = DenseMultilinear::from_eval_form(&eval_form);
+        let point = gen_random_vars::<Fq>(5);
+        assert_eq!(dense.evaluate(&point), eval_form.evaluate(&point));
+    }
+
+    #[test]
+    fn test_arithmetic() {
+        let p = DenseMultilinear::new(convert_to_fq_elements(vec![1, 0, 2, 3]));
+        let q = DenseMultilinear::new(convert_to_fq_elements(vec![4, 5, 0, 1]));
+        let point = [Fq::from(7), Fq::from(11)];
+        assert_eq!(
+            (&p + &q).evaluate(&point),
+            p.evaluate(&point) + q.evaluate(&point)
+        );
+        assert_eq!(
+            (p.clone() - q.clone()).evaluate(&point),
+            p.evaluate(&point) - q.evaluate(&point)
+        );
+        assert_eq!(-&p + p.clone(), DenseMultilinear::zero(2));
+        assert_eq!(
+            p.scalar_mul(Fq::from(3)).coefficients,
+            convert_to_fq_elements(vec![3, 0, 6, 9])
+        );
+    }
+
+    #[test]
+    fn test_tensor_mul() {
+        // (a + 1) * 2b = 2ab + 2b
+        let p = DenseMultilinear::new(convert_to_fq_elements(vec![1, 1]));
+        let q = DenseMultilinear::new(convert_to_fq_elements(vec![0, 2]));
+        let product = p.tensor_mul(&q);
+        assert_eq!(
+            product.coefficients,
+            convert_to_fq_elements(vec![0, 2, 0, 2])
+        );
+        assert_eq!(
+            product.evaluate(&[Fq::from(3), Fq::from(5)]),
+            p.evaluate(&[Fq::from(3)]) * q.evaluate(&[Fq::from(5)])
+        );
+    }
 
     #[test]
-    fn test_hello_world() {
-        assert_eq!("hello", "hello");
+    #[should_panic]
+    fn test_add_different_number_of_variables() {
+        let p = DenseMultilinear::new(convert_to_fq_elements(vec![1, 1]));
+        let q = DenseMultilinear::new(convert_to_fq_elements(vec![1, 1, 1, 1]));
+        let _ = p + q;
     }
 }
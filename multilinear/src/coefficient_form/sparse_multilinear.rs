@@ -1,16 +1,337 @@
+use crate::coefficient_form::dense_multilinear::DenseMultilinear;
+use crate::evaluation_form::MultilinearEvalForm;
+use crate::hypercube::variable_bit;
 use ark_ff::PrimeField;
-// pub struct Monomial <F:PrimeField>{
-//     coefficient : F,
-//     variables : Vec<F>
-// }
+use std::collections::BTreeMap;
+use std::ops::{Add, Mul};
+
+// ==============================================================//
+//    @note Sparse Multilinear Poly (monomial basis)
+// =============================================================//
+
+// every term is (monomial, coefficient) where the monomial is a bitmask of its variables
+// for f(a,b,c): a -> 0b100, b -> 0b010, c -> 0b001, so 3ac is (0b101, 3)
+// the first variable is the most significant bit, the same order as DenseMultilinear
+// terms are kept sorted by monomial, with no repeated monomials and no zero coefficients
+
+// bitmasks are usize, so at most usize::BITS variables
+pub const MAX_SPARSE_VARIABLES: u32 = usize::BITS;
+
+#[derive(Debug, Clone, PartialEq, Eq)]
 pub struct SparseMultilinear<F: PrimeField> {
-    pub poly_rep: Vec<(Vec<F>, F)>,
+    pub number_of_variables: u32,
+    pub terms: Vec<(usize, F)>,
 }
 
 impl<F: PrimeField> SparseMultilinear<F> {
-    pub fn new(polynomial: Vec<(Vec<F>, F)>) -> Self {
+    // terms may come in any order, repeated monomials are added together
+    pub fn new(number_of_variables: u32, terms: Vec<(usize, F)>) -> Self {
+        if number_of_variables > MAX_SPARSE_VARIABLES {
+            panic!("at most {} variables are supported", MAX_SPARSE_VARIABLES);
+        }
+        let mut merged: BTreeMap<usize, F> = BTreeMap::new();
+        for (monomial, coefficient) in terms {
+            if number_of_variables < MAX_SPARSE_VARIABLES && monomial >> number_of_variables != 0 {
+                panic!(
+                    "monomial {:b} uses more than {} variables",
+                    monomial, number_of_variables
+                );
+            }
+            *merged.entry(monomial).or_insert(F::zero()) += coefficient;
+        }
+        Self {
+            number_of_variables,
+            terms: merged
+                .into_iter()
+                .filter(|(_, coefficient)| !coefficient.is_zero())
+                .collect(),
+        }
+    }
+
+    pub fn zero(number_of_variables: u32) -> Self {
+        Self::new(number_of_variables, Vec::new())
+    }
+
+    pub fn is_zero(&self) -> bool {
+        self.terms.is_empty()
+    }
+
+    // keeps only the non-zero coefficients
+    pub fn from_dense(polynomial: &DenseMultilinear<F>) -> Self {
+        let terms = polynomial
+            .coefficients
+            .iter()
+            .enumerate()
+            .filter(|(_, coefficient)| !coefficient.is_zero())
+            .map(|(monomial, coefficient)| (monomial, *coefficient))
+            .collect();
         Self {
-            poly_rep: polynomial,
+            number_of_variables: polynomial.number_of_variables,
+            terms,
+        }
+    }
+
+    pub fn from_eval_form(polynomial: &MultilinearEvalForm<F>) -> Self {
+        Self::from_dense(&DenseMultilinear::from_eval_form(polynomial))
+    }
+
+    pub fn to_dense(&self) -> DenseMultilinear<F> {
+        let mut coefficients = vec![F::zero(); 1 << self.number_of_variables];
+        for (monomial, coefficient) in &self.terms {
+            coefficients[*monomial] = *coefficient;
+        }
+        DenseMultilinear::new(coefficients)
+    }
+
+    // the evaluations over the boolean hypercube, e.g to run sumcheck on a sparse constraint
+    pub fn to_eval_form(&self) -> MultilinearEvalForm<F> {
+        self.to_dense().to_eval_form()
+    }
+
+    // the order of the variables is important -> [a, b, c, d,...] for f(a,b,c,d,...)
+    // costs O(terms * n), independent of 2^n
+    pub fn evaluate(&self, variables: &[F]) -> F {
+        if variables.len() != self.number_of_variables as usize {
+            panic!("Invalid number of points")
+        }
+        let n = variables.len();
+        self.terms
+            .iter()
+            .map(|(monomial, coefficient)| {
+                variables
+                    .iter()
+                    .enumerate()
+                    .filter(|(i, _)| monomial >> (n - 1 - i) & 1 == 1)
+                    .fold(*coefficient, |product, (_, value)| product * value)
+            })
+            .sum()
+    }
+
+    // variable position: 1st , 2nd , 3rd etc, in a f(a,b,c) -> a is 1, b -> 2 , c -> 3
+    // returns a polynomial over the remaining variables in the same order, f(a, value, c) -> g(a, c)
+    pub fn partial_evaluate(&self, variable_position: u32, value: F) -> Self {
+        if variable_position == 0 || variable_position > self.number_of_variables {
+            panic!("Number of variables Exceeded!")
+        }
+        let bit = variable_bit(self.number_of_variables, variable_position);
+        let low_mask = (1usize << bit) - 1;
+        let terms = self
+            .terms
+            .iter()
+            .map(|(monomial, coefficient)| {
+                // drop the variable's bit and move the variables after it up by one
+                let high = monomial.checked_shr(bit + 1).unwrap_or(0) << bit;
+                let remaining = high | (monomial & low_mask);
+                if monomial >> bit & 1 == 1 {
+                    (remaining, *coefficient * value)
+                } else {
+                    (remaining, *coefficient)
+                }
+            })
+            .collect();
+        Self::new(self.number_of_variables - 1, terms)
+    }
+
+    // fixes the first values.len() variables, f(a, b, c) with [r] -> g(b, c) = f(r, b, c)
+    pub fn fix_variables(&self, values: &[F]) -> Self {
+        values.iter().fold(self.clone(), |polynomial, value| {
+            polynomial.partial_evaluate(1, *value)
+        })
+    }
+
+    // returns None when the product has a squared variable, i.e it is not multilinear
+    // both polynomials have to be over the same variables
+    pub fn checked_mul(&self, other: &SparseMultilinear<F>) -> Option<Self> {
+        if self.number_of_variables != other.number_of_variables {
+            panic!("Polynomials must be of the same number of variables");
+        }
+        // a product of two monomials is identified by the variables it uses and the squared ones,
+        // so terms that cancel out are merged before the check
+        let mut products: BTreeMap<(usize, usize), F> = BTreeMap::new();
+        for (left, a) in &self.terms {
+            for (right, b) in &other.terms {
+                *products
+                    .entry((left | right, left & right))
+                    .or_insert(F::zero()) += *a * b;
+            }
+        }
+        let mut terms = Vec::with_capacity(products.len());
+        for ((monomial, squared), coefficient) in products {
+            if coefficient.is_zero() {
+                continue;
+            }
+            if squared != 0 {
+                return None;
+            }
+            terms.push((monomial, coefficient));
+        }
+        Some(Self::new(self.number_of_variables, terms))
+    }
+}
+
+impl<F: PrimeField> Add for &SparseMultilinear<F> {
+    type Output = SparseMultilinear<F>;
+
+    fn add(self, other: &SparseMultilinear<F>) -> SparseMultilinear<F> {
+        if self.number_of_variables != other.number_of_variables {
+            panic!("Polynomials must be of the same number of variables");
         }
+        let terms = self
+            .terms
+            .iter()
+            .chain(other.terms.iter())
+            .copied()
+            .collect();
+        SparseMultilinear::new(self.number_of_variables, terms)
+    }
+}
+
+// panics when the product is not multilinear, see checked_mul
+impl<F: PrimeField> Mul for &SparseMultilinear<F> {
+    type Output = SparseMultilinear<F>;
+
+    fn mul(self, other: &SparseMultilinear<F>) -> SparseMultilinear<F> {
+        self.checked_mul(other)
+            .unwrap_or_else(|| panic!("product is not multilinear"))
+    }
+}
+
+impl<F: PrimeField> Add for SparseMultilinear<F> {
+    type Output = SparseMultilinear<F>;
+
+    fn add(self, other: SparseMultilinear<F>) -> SparseMultilinear<F> {
+        &self + &other
+    }
+}
+
+impl<F: PrimeField> Mul for SparseMultilinear<F> {
+    type Output = SparseMultilinear<F>;
+
+    fn mul(self, other: SparseMultilinear<F>) -> SparseMultilinear<F> {
+        &self * &other
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::evaluation_form::{convert_to_fq_elements, gen_based_on_two, gen_random_vars};
+    use ark_bn254::Fq;
+
+    // 3ac + b - 4 over (a, b, c)
+    fn get_sparse_poly() -> SparseMultilinear<Fq> {
+        SparseMultilinear::new(
+            3,
+            vec![
+                (0b101, Fq::from(3)),
+                (0b000, -Fq::from(4)),
+                (0b010, Fq::from(1)),
+            ],
+        )
+    }
+
+    #[test]
+    fn test_new_merges_terms() {
+        let poly = SparseMultilinear::new(
+            2,
+            vec![
+                (0b11, Fq::from(2)),
+                (0b01, Fq::from(5)),
+                (0b11, Fq::from(3)),
+                (0b01, -Fq::from(5)),
+            ],
+        );
+        assert_eq!(poly.terms, vec![(0b11, Fq::from(5))]);
+        assert!(SparseMultilinear::new(2, vec![(0b10, Fq::from(0))]).is_zero());
+    }
+
+    #[test]
+    #[should_panic]
+    fn test_new_monomial_out_of_range() {
+        SparseMultilinear::new(2, vec![(0b100, Fq::from(1))]);
+    }
+
+    #[test]
+    fn test_evaluate() {
+        let poly = get_sparse_poly();
+        // 3 * 2 * 5 + 7 - 4
+        let point = convert_to_fq_elements(vec![2, 7, 5]);
+        assert_eq!(poly.evaluate(&point), Fq::from(33));
+        assert_eq!(poly.to_eval_form().evaluate(&point), Fq::from(33));
+    }
+
+    #[test]
+    fn test_partial_evaluate() {
+        let poly = get_sparse_poly();
+        // b = 7 -> 3ac + 3 over (a, c)
+        let fixed = poly.partial_evaluate(2, Fq::from(7));
+        assert_eq!(fixed.number_of_variables, 2);
+        assert_eq!(fixed.terms, vec![(0b00, Fq::from(3)), (0b11, Fq::from(3))]);
+
+        let point = gen_random_vars::<Fq>(3);
+        assert_eq!(
+            poly.fix_variables(&point[..2]).evaluate(&point[2..]),
+            poly.evaluate(&point)
+        );
+        assert_eq!(
+            poly.fix_variables(&point[..1]).to_eval_form().eval_form,
+            poly.to_eval_form().fix_variables(&point[..1]).eval_form
+        );
+    }
+
+    #[test]
+    fn test_conversions() {
+        let eval_form = MultilinearEvalForm::new(gen_based_on_two::<Fq>(4));
+        let sparse = SparseMultilinear::from_eval_form(&eval_form);
+        assert_eq!(sparse.to_eval_form().eval_form, eval_form.eval_form);
+        assert_eq!(
+            sparse.to_dense(),
+            DenseMultilinear::from_eval_form(&eval_form)
+        );
+
+        let dense = get_sparse_poly().to_dense();
+        assert_eq!(dense.coefficient(&[0, 2]), Fq::from(3));
+        assert_eq!(SparseMultilinear::from_dense(&dense), get_sparse_poly());
+    }
+
+    #[test]
+    fn test_add() {
+        let poly = get_sparse_poly();
+        let other = SparseMultilinear::new(3, vec![(0b010, -Fq::from(1)), (0b001, Fq::from(2))]);
+        let sum = &poly + &other;
+        assert_eq!(
+            sum.terms,
+            vec![
+                (0b000, -Fq::from(4)),
+                (0b001, Fq::from(2)),
+                (0b101, Fq::from(3))
+            ]
+        );
+    }
+
+    #[test]
+    fn test_mul() {
+        // (a + 1)(2b - c) = 2ab - ac + 2b - c
+        let left = SparseMultilinear::new(3, vec![(0b100, Fq::from(1)), (0b000, Fq::from(1))]);
+        let right = SparseMultilinear::new(3, vec![(0b010, Fq::from(2)), (0b001, -Fq::from(1))]);
+        let product = &left * &right;
+        let point = gen_random_vars::<Fq>(3);
+        assert_eq!(
+            product.evaluate(&point),
+            left.evaluate(&point) * right.evaluate(&point)
+        );
+        assert_eq!(product.terms.len(), 4);
+
+        // a * a is not multilinear
+        assert_eq!(left.checked_mul(&left), None);
+        // neither is (a + b)(a - b) = a^2 - b^2, even though the ab terms cancel
+        let a_plus_b = SparseMultilinear::new(3, vec![(0b100, Fq::from(1)), (0b010, Fq::from(1))]);
+        let a_minus_b =
+            SparseMultilinear::new(3, vec![(0b100, Fq::from(1)), (0b010, -Fq::from(1))]);
+        assert_eq!(a_plus_b.checked_mul(&a_minus_b), None);
+        assert!(a_plus_b
+            .checked_mul(&SparseMultilinear::zero(3))
+            .unwrap()
+            .is_zero());
     }
 }
@@ -0,0 +1,182 @@
+use crate::evaluation_form::MultilinearEvalForm;
+use ark_ff::PrimeField;
+use std::sync::OnceLock;
+
+// ==============================================================//
+//    @note Equality Poly eq(x, r)
+// =============================================================//
+
+// eq(x, r) = prod_i (x_i * r_i + (1 - x_i) * (1 - r_i))
+// over the boolean hypercube it is 1 at x = r and 0 elsewhere, so sum_x eq(x, r) f(x) = f(r)
+// for any multilinear f, which is what zerocheck, GKR claim reduction and PCS openings rely on
+
+// the tables are built on first use and cached, the split tables need only 2 * 2^(n/2) elements
+// r is read only, so the cached tables always belong to it
+#[derive(Debug, Clone)]
+pub struct EqPolynomial<F: PrimeField> {
+    r: Vec<F>,
+    table: OnceLock<Vec<F>>,
+    split_tables: OnceLock<(Vec<F>, Vec<F>)>,
+}
+
+impl<F: PrimeField> EqPolynomial<F> {
+    pub fn new(r: Vec<F>) -> Self {
+        Self {
+            r,
+            table: OnceLock::new(),
+            split_tables: OnceLock::new(),
+        }
+    }
+
+    pub fn r(&self) -> &[F] {
+        &self.r
+    }
+
+    pub fn number_of_variables(&self) -> u32 {
+        self.r.len() as u32
+    }
+
+    // eq(r, s) in O(n), s does not have to be on the hypercube
+    pub fn evaluate(&self, s: &[F]) -> F {
+        if s.len() != self.r.len() {
+            panic!("Invalid number of points")
+        }
+        self.r
+            .iter()
+            .zip(s)
+            .map(|(r_i, s_i)| *r_i * s_i + (F::one() - r_i) * (F::one() - s_i))
+            .product()
+    }
+
+    // eq(x, r) for every x in the hypercube, the first variable is the most significant bit
+    pub fn evaluations(&self) -> &[F] {
+        self.table.get_or_init(|| build_eq_table(&self.r))
+    }
+
+    // the table as an ordinary multilinear polynomial, e.g a factor of a ProdPoly
+    pub fn to_eval_form(&self) -> MultilinearEvalForm<F> {
+        MultilinearEvalForm::new(self.evaluations().to_vec())
+    }
+
+    // eq(x, r) = eq(x_high, r_high) * eq(x_low, r_low), with the first n / 2 variables high
+    // returns (high table, low table)
+    pub fn split_tables(&self) -> &(Vec<F>, Vec<F>) {
+        self.split_tables.get_or_init(|| {
+            let (r_high, r_low) = self.r.split_at(self.r.len() / 2);
+            (build_eq_table(r_high), build_eq_table(r_low))
+        })
+    }
+
+    // eq(x, r) at the hypercube point with this index, read from the split tables
+    pub fn split_evaluation(&self, index: usize) -> F {
+        let (high, low) = self.split_tables();
+        high[index / low.len()] * low[index % low.len()]
+    }
+
+    // sum_x eq(x, r) * values[x], i.e the multilinear extension of values at r,
+    // without materialising the full table
+    pub fn inner_product(&self, values: &[F]) -> F {
+        let (high, low) = self.split_tables();
+        if values.len() != high.len() * low.len() {
+            panic!("values must cover the boolean hypercube");
+        }
+        high.iter()
+            .zip(values.chunks(low.len()))
+            .map(|(high_eq, chunk)| {
+                let inner: F = low.iter().zip(chunk).map(|(l, v)| *l * v).sum();
+                *high_eq * inner
+            })
+            .sum()
+    }
+}
+
+// eq(x, r) for every x, one multiplication per entry
+// each variable doubles the table: t -> (t * (1 - r_i), t * r_i) = (t - t * r_i, t * r_i)
+pub fn build_eq_table<F: PrimeField>(r: &[F]) -> Vec<F> {
+    let mut table = vec![F::zero(); 1 << r.len()];
+    table[0] = F::one();
+    let mut size = 1;
+    for r_i in r {
+        // walk down so every entry is read before its slot is overwritten
+        for j in (0..size).rev() {
+            let t = table[j];
+            let high = t * r_i;
+            table[2 * j + 1] = high;
+            table[2 * j] = t - high;
+        }
+        size *= 2;
+    }
+    table
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::evaluation_form::{gen_based_on_two, gen_random_vars, ProdPoly};
+    use ark_bn254::Fq;
+
+    // the hypercube point with this index, first variable most significant
+    fn to_point(index: usize, n: usize) -> Vec<Fq> {
+        (0..n)
+            .map(|i| Fq::from(((index >> (n - 1 - i)) & 1) as u64))
+            .collect()
+    }
+
+    #[test]
+    fn test_eq_table() {
+        let eq = EqPolynomial::new(gen_random_vars::<Fq>(4));
+        let table = eq.evaluations();
+        assert_eq!(table.len(), 16);
+        for (index, value) in table.iter().enumerate() {
+            assert_eq!(*value, eq.evaluate(&to_point(index, 4)));
+        }
+        assert_eq!(table.iter().sum::<Fq>(), Fq::from(1));
+    }
+
+    #[test]
+    fn test_eq_at_boolean_point() {
+        let r = to_point(0b101, 3);
+        let eq = EqPolynomial::new(r.clone());
+        let mut expected = vec![Fq::from(0); 8];
+        expected[0b101] = Fq::from(1);
+        assert_eq!(eq.evaluations(), &expected[..]);
+        assert_eq!(eq.r(), &r[..]);
+        assert_eq!(eq.evaluate(&r), Fq::from(1));
+        assert_eq!(eq.evaluate(&to_point(0b100, 3)), Fq::from(0));
+    }
+
+    #[test]
+    fn test_split_tables() {
+        for n in [0, 1, 4, 5] {
+            let eq = EqPolynomial::new(gen_random_vars::<Fq>(n));
+            let (high, low) = eq.split_tables();
+            assert_eq!(high.len() * low.len(), 1 << n);
+            for (index, value) in eq.evaluations().iter().enumerate() {
+                assert_eq!(eq.split_evaluation(index), *value);
+            }
+        }
+    }
+
+    #[test]
+    fn test_inner_product_is_mle_evaluation() {
+        let r = gen_random_vars::<Fq>(5);
+        let poly = MultilinearEvalForm::new(gen_based_on_two::<Fq>(5));
+        let eq = EqPolynomial::new(r.clone());
+        assert_eq!(eq.inner_product(&poly.eval_form), poly.evaluate(&r));
+    }
+
+    #[test]
+    fn test_eq_as_prod_poly_factor() {
+        let r = gen_random_vars::<Fq>(3);
+        let poly = MultilinearEvalForm::new(gen_based_on_two::<Fq>(3));
+        let eq = EqPolynomial::new(r.clone());
+        let prod_poly = ProdPoly::new(vec![eq.to_eval_form(), poly.clone()]);
+        assert_eq!(prod_poly.reduce().iter().sum::<Fq>(), poly.evaluate(&r));
+
+        let point = gen_random_vars::<Fq>(3);
+        assert_eq!(
+            prod_poly.evaluate(&point),
+            eq.evaluate(&point) * poly.evaluate(&point)
+        );
+    }
+}
@@ -1,6 +1,12 @@
+use crate::hypercube::{check_positions, gather, scatter, variable_bit, VariableOrder};
+use crate::parallel::{cfg_chunks_mut, cfg_into_iter, cfg_iter, cfg_iter_mut};
 use ark_bn254::Fq;
 use ark_ff::{BigInteger, PrimeField};
-use std::collections::HashSet;
+#[cfg(feature = "parallel")]
+use rayon::prelude::*;
+
+pub mod eq_polynomial;
+pub mod virtual_polynomial;
 // use ci
 // update this to use binary instead of decimal
 #[derive(Clone)]
@@ -10,16 +16,26 @@ use std::collections::HashSet;
 // =============================================================//
 
 pub struct MultilinearEvalForm<F: PrimeField> {
+    // always log2(eval_form.len()), every method that shrinks the table updates it
     pub number_of_variables: u32,
     pub eval_form: Vec<F>,
-    pub boolean_hypercube: Vec<u32>,
 }
 
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
 pub enum Op {
     Add,
     Mul,
 }
 
+impl Op {
+    pub fn apply<F: PrimeField>(&self, a: F, b: F) -> F {
+        match self {
+            Op::Add => a + b,
+            Op::Mul => a * b,
+        }
+    }
+}
+
 impl<F: PrimeField> MultilinearEvalForm<F> {
     pub fn new(eval_form: Vec<F>) -> Self {
         // if the length of the list is n, then
@@ -29,89 +45,231 @@ impl<F: PrimeField> MultilinearEvalForm<F> {
         Self {
             eval_form,
             number_of_variables: no_of_vars,
-            boolean_hypercube: (0..n).collect(),
         }
     }
     // variable position: 1st , 2nd , 3rd etc
     // in a f(a,b,c) -> a is 1, b -> 2 , c -> 3
+    // positions count the variables that are left, as in fold and fix_variables, so after fixing a,
+    // b is 1 and c is 2, while after fixing c, a and b keep their positions
+    // the table is always big endian (hypercube::VariableOrder), convert other layouts with from_ordered
     pub fn partial_evaluate(&mut self, variable_position: u32, value: F) -> Vec<F> {
-        // Since we have just 2 bits representing two vars, -> a , b
-        // 01 -> our target is b
-        // 10 -> our target is a
         if variable_position == 0 || variable_position > self.number_of_variables {
             panic!("Number of variables Exceeded!")
         }
         // for F(a,b,c)
-        // 1 -> 100 -> a
-        // 2 -> 010 -> b
-        // 3 -> 001 -> c
-        // println!("entered here -> {:?}",self.eval_form);
-        // 2 ^ (number_of_vars - variable_position)
-        let target = 2u32.pow(self.number_of_variables - variable_position);
-        let pairings = find_pairs_with_xor(&self.boolean_hypercube, target);
-        let mut new_vec: Vec<F> = Vec::new();
-
-        for pair in pairings {
-            let index_one = pair.0;
-            let index_two = pair.1;
-            let v = interpolate_and_evaluate(
-                (
-                    self.eval_form[index_one as usize],
-                    self.eval_form[index_two as usize],
-                ),
-                value,
-            );
-            new_vec.push(v);
+        // 1 -> 100 -> a -> stride 4
+        // 2 -> 010 -> b -> stride 2
+        // 3 -> 001 -> c -> stride 1
+        // the pairs differing only in the variable's bit are `stride` apart
+        let stride = 1usize << variable_bit(self.number_of_variables, variable_position);
+        let length = self.eval_form.len();
+        // every block of 2 * stride evaluations folds into its first half
+        cfg_chunks_mut!(self.eval_form, 2 * stride).for_each(|block| {
+            let (low, high) = block.split_at_mut(stride);
+            cfg_iter_mut!(low)
+                .zip(cfg_iter!(high))
+                .for_each(|(y_0, y_1)| *y_0 = interpolate_and_evaluate((*y_0, *y_1), value));
+        });
+        // then the folded halves are packed together, no field operations
+        for block in 1..length / (2 * stride) {
+            self.eval_form
+                .copy_within(2 * block * stride..(2 * block + 1) * stride, block * stride);
         }
-        // if new_vec.is_empty() {
-        //     panic!("Partial evaluation resulted in an empty polynomial! Check variable positions.");
-        // }
-        self.eval_form = new_vec;
-        self.boolean_hypercube = (0..self.eval_form.len() as u32).collect();
+        self.eval_form.truncate(length / 2);
+        self.number_of_variables -= 1;
         self.eval_form.clone()
     }
 
     // the order of the variables is important -> [a, b, c, d,...] for f(a,b,c,d,...)
-    pub fn evaluate(&mut self, variables: &Vec<F>) -> F {
+    // the polynomial is left untouched, so it can be evaluated again
+    pub fn evaluate(&self, variables: &[F]) -> F {
         if variables.len() != self.number_of_variables as usize {
             panic!("Invalid number of points")
         }
-        // dbg!(&variables, &self.eval_form);
-        for (i, var) in variables.iter().enumerate() {
-            self.partial_evaluate((i + 1) as u32, *var);
+        self.fix_variables(variables).eval_form[0]
+    }
+
+    // fixes the first values.len() variables, f(a, b, c) with [r] -> g(b, c) = f(r, b, c)
+    // returns a new polynomial over the remaining variables
+    pub fn fix_variables(&self, values: &[F]) -> Self {
+        if values.len() > self.number_of_variables as usize {
+            panic!("Number of variables Exceeded!")
         }
-        if self.eval_form.is_empty() {
-            panic!("polynomial is empty!");
+        let Some((first, rest)) = values.split_first() else {
+            return self.clone();
+        };
+        // the first fold reads from self and writes into a table of half the size
+        let (low, high) = self.eval_form.split_at(self.eval_form.len() / 2);
+        let eval_form: Vec<F> = cfg_iter!(low)
+            .zip(cfg_iter!(high))
+            .map(|(y_0, y_1)| interpolate_and_evaluate((*y_0, *y_1), *first))
+            .collect();
+        let mut fixed = MultilinearEvalForm::new(eval_form);
+        for value in rest {
+            fixed.fold(*value);
         }
-        self.eval_form[0]
+        fixed
     }
 
-    // the assumption here are:
-    // both w_b and w_c are have the same number of variables
-    /// both w_b and w_c have different variables
-    pub fn tensor_add_or_mul(
-        w_b: &MultilinearEvalForm<F>,
-        w_c: &MultilinearEvalForm<F>, // might change this to accept just one poly
-        op: Op,
-    ) -> MultilinearEvalForm<F> {
-        let length = w_b.eval_form.len();
-        if length != w_c.eval_form.len() {
-            panic!("w_b and w_c must be the length!");
+    // fixes the first remaining variable in place, f(a, b, c) -> f(value, b, c) as a polynomial in (b, c)
+    // this is one sumcheck round: the table halves and number_of_variables drops by one
+    pub fn fold(&mut self, value: F) {
+        self.fold_high(value);
+    }
+
+    // fixes the first remaining variable (the most significant bit), the same as fold
+    pub fn fold_high(&mut self, value: F) {
+        let half = self.eval_form.len() / 2;
+        if half == 0 {
+            panic!("no variables left to fold");
+        }
+        let (low, high) = self.eval_form.split_at_mut(half);
+        cfg_iter_mut!(low)
+            .zip(cfg_iter!(high))
+            .for_each(|(y_0, y_1)| *y_0 = interpolate_and_evaluate((*y_0, *y_1), value));
+        self.eval_form.truncate(half);
+        self.number_of_variables -= 1;
+    }
+
+    // fixes the last remaining variable (the least significant bit) in place
+    // f(a, b, c) -> f(a, b, value) as a polynomial in (a, b)
+    pub fn fold_low(&mut self, value: F) {
+        let half = self.eval_form.len() / 2;
+        if half == 0 {
+            panic!("no variables left to fold");
         }
-        let mut result = vec![F::zero(); 2u32.pow(length as u32) as usize];
-        for (i, element_a) in w_b.eval_form.iter().enumerate() {
-            for (j, element_b) in w_c.eval_form.iter().enumerate() {
-                let result_index =
-                    combine_convert(vec![i as u32, j as u32], w_b.number_of_variables as usize);
-                result[result_index as usize] = match op {
-                    Op::Add => *element_a + *element_b,
-                    Op::Mul => *element_a * *element_b,
-                }
+        // the pairs differing only in the last variable sit next to each other
+        let eval_form = &self.eval_form;
+        let folded: Vec<F> = cfg_into_iter!(0..half)
+            .map(|i| interpolate_and_evaluate((eval_form[2 * i], eval_form[2 * i + 1]), value))
+            .collect();
+        self.eval_form = folded;
+        self.number_of_variables -= 1;
+    }
+
+    // evaluations over the hypercube stored in the given order, e.g with LittleEndian
+    // eval_form[0b001] is f(1, 0, 0) for f(a, b, c)
+    pub fn from_ordered(eval_form: Vec<F>, order: VariableOrder) -> Self {
+        match order {
+            VariableOrder::BigEndian => MultilinearEvalForm::new(eval_form),
+            VariableOrder::LittleEndian => {
+                let n = get_power_of_two(eval_form.len() as u32);
+                let reordered = (0..eval_form.len())
+                    .map(|index| eval_form[order.to_big_endian(index, n)])
+                    .collect();
+                MultilinearEvalForm::new(reordered)
             }
         }
+    }
+
+    // the evaluations laid out in the given order, the inverse of from_ordered
+    pub fn to_ordered(&self, order: VariableOrder) -> Vec<F> {
+        // reversing the bits twice gives the index back, so the same reordering converts both ways
+        MultilinearEvalForm::from_ordered(self.eval_form.clone(), order).eval_form
+    }
+
+    // reorders the variables, the i-th variable of the result is the variable of self
+    // at position permutation[i] (1-based), i.e g(x_1, .., x_n) = f(y) with y_permutation[i] = x_i
+    // e.g [3, 1, 2] turns f(a, b, c) into g(c, a, b) = f(a, b, c)
+    pub fn permute_variables(&self, permutation: &[u32]) -> Self {
+        let n = self.eval_form.len().trailing_zeros();
+        if permutation.len() != n as usize {
+            panic!("the permutation must cover all {} variables", n);
+        }
+        check_positions(n, permutation);
+        MultilinearEvalForm::from_fn(n, |index| self.eval_form[scatter(index, n, permutation)])
+    }
+
+    // pads values up to the next power of two with pad, e.g a GKR layer with 3 gates
+    // an empty list becomes the constant pad
+    pub fn from_padded(mut values: Vec<F>, pad: F) -> Self {
+        values.resize(values.len().next_power_of_two(), pad);
+        MultilinearEvalForm::new(values)
+    }
+
+    // the polynomial taking f(index) at every point of the hypercube, first variable most significant
+    pub fn from_fn<G>(number_of_variables: u32, f: G) -> Self
+    where
+        G: Fn(usize) -> F + Sync + Send,
+    {
+        let eval_form = cfg_into_iter!(0..1usize << number_of_variables)
+            .map(f)
+            .collect();
+        MultilinearEvalForm::new(eval_form)
+    }
+
+    // embeds the polynomial into a hypercube of number_of_variables variables, its i-th variable
+    // becomes the variable at positions[i] (1-based) and the others are dummy variables it ignores
+    // e.g f(a, b) with positions [1, 3] over 3 variables -> g(a, x, b) = f(a, b)
+    pub fn extend_variables(&self, number_of_variables: u32, positions: &[u32]) -> Self {
+        if positions.len() != self.eval_form.len().trailing_zeros() as usize {
+            panic!("every variable needs a position");
+        }
+        check_positions(number_of_variables, positions);
+        MultilinearEvalForm::from_fn(number_of_variables, |index| {
+            self.eval_form[gather(index, number_of_variables, positions)]
+        })
+    }
+
+    // restricts the polynomial to the sub-cube where the given variables take the given values,
+    // what remains is a polynomial over the other variables in their original order
+    // e.g f(a, b, c) with [(2, true)] -> g(a, c) = f(a, 1, c)
+    pub fn restrict(&self, fixed: &[(u32, bool)]) -> Self {
+        let n = self.eval_form.len().trailing_zeros();
+        let fixed_positions: Vec<u32> = fixed.iter().map(|(position, _)| *position).collect();
+        check_positions(n, &fixed_positions);
+        let fixed_bits = fixed
+            .iter()
+            .filter(|(_, value)| *value)
+            .fold(0, |bits, (position, _)| {
+                bits | 1 << variable_bit(n, *position)
+            });
+        let remaining: Vec<u32> = (1..=n)
+            .filter(|position| !fixed_positions.contains(position))
+            .collect();
+        MultilinearEvalForm::from_fn(remaining.len() as u32, |index| {
+            self.eval_form[fixed_bits | scatter(index, n, &remaining)]
+        })
+    }
+
+    // the tensor (outer) product of polynomials over disjoint variables, combined with op
+    // for f(a, b) and g(c) the result is h(a, b, c) = op(f(a, b), g(c))
+    // the operands can have any number of variables, the first operand's variables come first,
+    // so the result index is the operands' indices packed one after the other, e.g 0b10 and 0b1 -> 0b101
+    // (hypercube::pack)
+    pub fn tensor<O>(polynomials: &[&MultilinearEvalForm<F>], op: O) -> MultilinearEvalForm<F>
+    where
+        O: Fn(F, F) -> F + Sync,
+    {
+        let Some((first, rest)) = polynomials.split_first() else {
+            panic!("the tensor product needs at least one polynomial");
+        };
+        let mut result = first.eval_form.clone();
+        for polynomial in rest {
+            let width = polynomial.eval_form.len();
+            let mut combined = vec![F::zero(); result.len() * width];
+            // every entry of the result so far is the high bits of one block of width entries
+            cfg_chunks_mut!(combined, width)
+                .zip(cfg_iter!(result))
+                .for_each(|(block, a)| {
+                    for (entry, b) in block.iter_mut().zip(polynomial.eval_form.iter()) {
+                        *entry = op(*a, *b);
+                    }
+                });
+            result = combined;
+        }
         MultilinearEvalForm::new(result)
     }
 
+    // W(b) op W(c) for a GKR layer, w_b and w_c may have different numbers of variables
+    pub fn tensor_add_or_mul(
+        w_b: &MultilinearEvalForm<F>,
+        w_c: &MultilinearEvalForm<F>,
+        op: Op,
+    ) -> MultilinearEvalForm<F> {
+        MultilinearEvalForm::tensor(&[w_b, w_c], |a, b| op.apply(a, b))
+    }
+
     pub fn tensor_add(
         w_b: &MultilinearEvalForm<F>,
         w_c: &MultilinearEvalForm<F>,
@@ -151,41 +309,6 @@ pub fn convert_to_fq_elements(values: Vec<u32>) -> Vec<Fq> {
     values.into_iter().map(|x| Fq::from(x)).collect()
 }
 
-// this function takes in a vec of indices e.g [1,2,3]
-// converts each into binary and concatenate the result based on the digit
-// e.g if digit is 2 ->
-// 1 -> 01; 2 -> 10; 3 -> 11
-// output -> 011011
-
-// if digit is 3 ->
-// 1 -> 001 ; 2 -> 010; 3 -> 011;
-// output-> 001010011
-
-// it returns a decimal output d of the concatenated binary values
-pub fn combine_convert(values: Vec<u32>, digit: usize) -> u32 {
-    let binary_string: String = values
-        .iter()
-        .map(|&x| format!("{:0digit$b}", x, digit = digit))
-        .collect();
-    u32::from_str_radix(&binary_string, 2).unwrap()
-}
-
-// this function uses XOR gate to pair the entities of the boolean hypercube
-pub fn find_pairs_with_xor(nums: &[u32], target: u32) -> Vec<(u32, u32)> {
-    let mut result = Vec::new();
-    let mut seen = HashSet::new();
-
-    for &num in nums {
-        let complement = num ^ target;
-        if seen.contains(&complement) {
-            result.push((complement, num));
-        }
-        seen.insert(num);
-    }
-
-    result
-}
-
 // suppose we want to evaluate y1 -> y2   at r
 pub fn interpolate_and_evaluate<F: PrimeField>(y_values: (F, F), r: F) -> F {
     y_values.0 + r * (y_values.1 - y_values.0)
@@ -241,18 +364,36 @@ impl<F: PrimeField> ProdPoly<F> {
         for poly in &mut self.polynomials {
             poly.partial_evaluate(variable_position, value);
         }
+        self.no_of_vars = self.polynomials[0].number_of_variables;
         self.reduce()
     }
 
-    pub fn evaluate(&mut self, variables: &Vec<F>) -> F {
-        let mut product = F::one();
+    pub fn evaluate(&self, variables: &[F]) -> F {
         if (variables.len() as u32) != self.no_of_vars {
             panic!("Invalid variable length!");
         }
+        self.polynomials
+            .iter()
+            .map(|poly| poly.evaluate(variables))
+            .product()
+    }
+
+    // fixes the first values.len() variables of every factor
+    pub fn fix_variables(&self, values: &[F]) -> Self {
+        ProdPoly::new(
+            self.polynomials
+                .iter()
+                .map(|poly| poly.fix_variables(values))
+                .collect(),
+        )
+    }
+
+    // fixes the first remaining variable of every factor in place
+    pub fn fold(&mut self, value: F) {
         for poly in &mut self.polynomials {
-            product *= poly.evaluate(&variables);
+            poly.fold(value);
         }
-        product
+        self.no_of_vars = self.polynomials[0].number_of_variables;
     }
 
     // converts the polynomial to an array of bytes (u8)
@@ -266,13 +407,13 @@ impl<F: PrimeField> ProdPoly<F> {
     // this function reduces the product poly to a simple boolean hypercube representation in evaluation form
     pub fn reduce(&self) -> Vec<F> {
         let end = self.polynomials[0].eval_form.len();
-        let mut output = vec![F::one(); end];
-        for poly in &self.polynomials {
-            for i in 0..end {
-                output[i] *= poly.eval_form[i]
-            }
-        }
-        output
+        cfg_into_iter!(0..end)
+            .map(|i| {
+                self.polynomials
+                    .iter()
+                    .fold(F::one(), |product, poly| product * poly.eval_form[i])
+            })
+            .collect()
     }
 }
 
@@ -299,27 +440,43 @@ impl<F: PrimeField> SumPoly<F> {
             poly.partial_evaluate(variable_position, value);
         }
     }
-    pub fn evaluate(&mut self, variables: &Vec<F>) -> F {
-        let mut result: F = F::zero();
+    pub fn evaluate(&self, variables: &[F]) -> F {
+        self.product_polys
+            .iter()
+            .map(|poly| poly.evaluate(variables))
+            .sum()
+    }
+
+    // fixes the first values.len() variables of every product poly
+    pub fn fix_variables(&self, values: &[F]) -> Self {
+        SumPoly::new(
+            self.product_polys
+                .iter()
+                .map(|poly| poly.fix_variables(values))
+                .collect(),
+        )
+    }
+
+    // fixes the first remaining variable of every product poly in place
+    pub fn fold(&mut self, value: F) {
         for poly in &mut self.product_polys {
-            let m = poly.evaluate(variables);
-            result += m;
+            poly.fold(value);
         }
-        result
     }
 
     // this function reduces the sum poly to a simple boolean hypercube representation in evaluation form
     pub fn reduce(&self) -> Vec<F> {
         let length = self.product_polys[0].no_of_vars;
-        let mut result = vec![F::zero(); 2u32.pow(length) as usize];
-        // let mut reduced_form:Vec<Vec<F>> = Vec::new();
-        for prod_poly in &self.product_polys {
-            let reduced_form = prod_poly.reduce();
-            for (i, ele) in reduced_form.iter().enumerate() {
-                result[i] += ele;
-            }
-        }
-        result
+        let reduced_forms: Vec<Vec<F>> = cfg_iter!(self.product_polys)
+            .map(|prod_poly| prod_poly.reduce())
+            .collect();
+        cfg_into_iter!(0..2usize.pow(length))
+            .map(|i| {
+                reduced_forms
+                    .iter()
+                    .fold(F::zero(), |sum, reduced_form| sum + reduced_form[i])
+            })
+            .collect()
     }
 
     // get number of variables
@@ -342,13 +499,14 @@ impl<F: PrimeField> SumPoly<F> {
 #[cfg(test)]
 pub mod tests {
     use super::*;
+    use crate::hypercube::pairs;
 
     #[test]
     fn test_create_rep() {
         let eval_form =
             MultilinearEvalForm::new(vec![Fq::from(1), Fq::from(2), Fq::from(3), Fq::from(4)]);
         assert_eq!(eval_form.number_of_variables, 2);
-        assert_eq!(eval_form.boolean_hypercube, vec![0, 1, 2, 3]);
+        assert_eq!(eval_form.eval_form.len(), 4);
     }
 
     #[test]
@@ -363,43 +521,6 @@ pub mod tests {
         ]);
     }
 
-    #[test]
-    fn test_finding_pairs() {
-        let num = 4;
-        let boolean_hypercube_of_2_vars = (0..num).collect::<Vec<u32>>();
-        // Since we have just 2 bits representing two vars, -> a , b
-        // 1 -> our target is b
-        // 2 -> our target is a
-        let a_target = 2;
-        let b_target = 1;
-        let pairs_for_a = find_pairs_with_xor(&boolean_hypercube_of_2_vars, a_target);
-        assert_eq!(pairs_for_a, vec![(0, 2), (1, 3)]);
-
-        let pairs_for_b = find_pairs_with_xor(&boolean_hypercube_of_2_vars, b_target);
-        assert_eq!(pairs_for_b, vec![(0, 1), (2, 3)]);
-    }
-
-    #[test]
-    fn test_finding_pairs_for_3vars() {
-        let num = 8;
-        let boolean_hypercube_of_2_vars = (0..num).collect::<Vec<u32>>();
-        // Since we have just 2 bits representing two vars, -> a , b
-        // 01 -> our target is b
-        // 10 -> our target is a
-        let a_target = 4;
-        let b_target = 2;
-        let c_target = 1;
-
-        let pairs_for_a = find_pairs_with_xor(&boolean_hypercube_of_2_vars, a_target);
-        assert_eq!(pairs_for_a, vec![(0, 4), (1, 5), (2, 6), (3, 7)]);
-
-        let pairs_for_b = find_pairs_with_xor(&boolean_hypercube_of_2_vars, b_target);
-        assert_eq!(pairs_for_b, vec![(0, 2), (1, 3), (4, 6), (5, 7)]);
-
-        let pairs_for_c = find_pairs_with_xor(&boolean_hypercube_of_2_vars, c_target);
-        assert_eq!(pairs_for_c, vec![(0, 1), (2, 3), (4, 5), (6, 7)]);
-    }
-
     #[test]
     fn test_interpolate_and_evaluate() {
         let y_values = (Fq::from(1), Fq::from(2));
@@ -412,7 +533,7 @@ pub mod tests {
         let mut poly = MultilinearEvalForm::new(vec![Fq::from(4), Fq::from(7)]);
         poly.partial_evaluate(1, Fq::from(3));
         assert_eq!(poly.eval_form, vec![Fq::from(13)]);
-        assert_eq!(poly.number_of_variables, 1);
+        assert_eq!(poly.number_of_variables, 0);
     }
 
     #[test]
@@ -421,7 +542,7 @@ pub mod tests {
             MultilinearEvalForm::new(vec![Fq::from(0), Fq::from(3), Fq::from(2), Fq::from(5)]);
         poly.partial_evaluate(1, Fq::from(2));
         assert_eq!(poly.eval_form, vec![Fq::from(4), Fq::from(7)]);
-        assert_eq!(poly.number_of_variables, 2);
+        assert_eq!(poly.number_of_variables, 1);
     }
 
     #[test]
@@ -441,19 +562,351 @@ pub mod tests {
             poly.eval_form,
             vec![Fq::from(0), Fq::from(9), Fq::from(0), Fq::from(11)]
         );
-        assert_eq!(poly.number_of_variables, 3);
+        assert_eq!(poly.number_of_variables, 2);
+    }
+
+    #[test]
+    fn test_partial_evaluate_matches_pairs() {
+        let values = gen_based_on_two::<Fq>(4);
+        let r = Fq::from(7);
+        for variable_position in 1..=4u32 {
+            let expected: Vec<Fq> = pairs(4, variable_position)
+                .map(|(i, j)| interpolate_and_evaluate((values[i], values[j]), r))
+                .collect();
+            let mut poly = MultilinearEvalForm::new(values.clone());
+            assert_eq!(poly.partial_evaluate(variable_position, r), expected);
+        }
+    }
+
+    #[test]
+    fn test_partial_evaluate_skipping_a_variable() {
+        let values = gen_based_on_two::<Fq>(3);
+        let (a, c) = (Fq::from(5), Fq::from(9));
+        // fixing a and then c, the second of (b, c), leaves a polynomial in b
+        let mut poly = MultilinearEvalForm::new(values.clone());
+        poly.partial_evaluate(1, a);
+        poly.partial_evaluate(2, c);
+        let expected: Vec<Fq> = [Fq::from(0), Fq::from(1)]
+            .iter()
+            .map(|b| MultilinearEvalForm::new(values.clone()).evaluate(&[a, *b, c]))
+            .collect();
+        assert_eq!(poly.eval_form, expected);
+    }
+
+    #[test]
+    fn test_partial_evaluate_in_reverse_order() {
+        let values = gen_based_on_two::<Fq>(3);
+        let (a, b, c) = (Fq::from(5), Fq::from(7), Fq::from(9));
+        // c, then b, then a, each the last variable left
+        let mut poly = MultilinearEvalForm::new(values.clone());
+        poly.partial_evaluate(3, c);
+        assert_eq!(poly.number_of_variables, 2);
+        poly.partial_evaluate(2, b);
+        assert_eq!(poly.number_of_variables, 1);
+        let expected: Vec<Fq> = [Fq::from(0), Fq::from(1)]
+            .iter()
+            .map(|x| MultilinearEvalForm::new(values.clone()).evaluate(&[*x, b, c]))
+            .collect();
+        assert_eq!(poly.eval_form, expected);
+        poly.partial_evaluate(1, a);
+        assert_eq!(
+            poly.eval_form,
+            vec![MultilinearEvalForm::new(values).evaluate(&[a, b, c])]
+        );
+        assert_eq!(poly.number_of_variables, 0);
+    }
+
+    #[test]
+    #[should_panic]
+    fn test_partial_evaluate_past_the_remaining_variables() {
+        // after fixing c only (a, b) are left
+        let mut poly = MultilinearEvalForm::new(gen_based_on_two::<Fq>(3));
+        poly.partial_evaluate(3, Fq::from(2));
+        poly.partial_evaluate(3, Fq::from(2));
     }
 
     #[test]
     fn test_evaluate_for_2vars() {
-        let mut eval_form =
+        let eval_form =
             MultilinearEvalForm::new(vec![Fq::from(0), Fq::from(3), Fq::from(2), Fq::from(5)]);
         assert_eq!(
-            eval_form.evaluate(&vec![Fq::from(2), Fq::from(3)]),
+            eval_form.evaluate(&[Fq::from(2), Fq::from(3)]),
             Fq::from(13)
         );
     }
 
+    #[test]
+    fn test_evaluate_leaves_polynomial_intact() {
+        let poly =
+            MultilinearEvalForm::new(vec![Fq::from(0), Fq::from(3), Fq::from(2), Fq::from(5)]);
+        let point = [Fq::from(2), Fq::from(3)];
+        assert_eq!(poly.evaluate(&point), Fq::from(13));
+        assert_eq!(poly.evaluate(&point), Fq::from(13));
+        assert_eq!(poly.number_of_variables, 2);
+        assert_eq!(poly.eval_form.len(), 4);
+    }
+
+    #[test]
+    fn test_fix_variables_and_fold() {
+        let poly = MultilinearEvalForm::new(gen_based_on_two::<Fq>(4));
+        let point = gen_random_vars::<Fq>(4);
+
+        let fixed = poly.fix_variables(&point[..2]);
+        assert_eq!(fixed.number_of_variables, 2);
+        assert_eq!(fixed.evaluate(&point[2..]), poly.evaluate(&point));
+        assert_eq!(poly.fix_variables(&[]).eval_form, poly.eval_form);
+
+        let mut folded = poly.clone();
+        folded.fold(point[0]);
+        folded.fold(point[1]);
+        assert_eq!(folded.eval_form, fixed.eval_form);
+        assert_eq!(folded.number_of_variables, 2);
+
+        // folding the first variable is the same as partially evaluating it
+        let mut partial = poly.clone();
+        partial.partial_evaluate(1, point[0]);
+        assert_eq!(partial.eval_form, poly.fix_variables(&point[..1]).eval_form);
+    }
+
+    #[test]
+    fn test_fold_high_and_low() {
+        // f(a, b) = 3ab + 2a + 1 takes 1, 1, 3, 6 on 00, 01, 10, 11
+        let poly = MultilinearEvalForm::new(convert_to_fq_elements(vec![1, 1, 3, 6]));
+        // a = 2 -> 6b + 5
+        let mut high = poly.clone();
+        high.fold_high(Fq::from(2));
+        assert_eq!(high.eval_form, convert_to_fq_elements(vec![5, 11]));
+        // b = 2 -> 8a + 1
+        let mut low = poly.clone();
+        low.fold_low(Fq::from(2));
+        assert_eq!(low.eval_form, convert_to_fq_elements(vec![1, 9]));
+        assert_eq!(low.number_of_variables, 1);
+
+        // folding from either end reaches the same point
+        let poly = MultilinearEvalForm::new(gen_based_on_two::<Fq>(4));
+        let point = gen_random_vars::<Fq>(4);
+        let mut folded = poly.clone();
+        folded.fold_low(point[3]);
+        folded.fold_high(point[0]);
+        folded.fold_low(point[2]);
+        folded.fold(point[1]);
+        assert_eq!(folded.eval_form, vec![poly.evaluate(&point)]);
+    }
+
+    #[test]
+    fn test_same_polynomial_in_every_order() {
+        let poly = MultilinearEvalForm::new(gen_based_on_two::<Fq>(3));
+        let point = gen_random_vars::<Fq>(3);
+        let expected = poly.evaluate(&point);
+
+        // f(a, b, c) stored with a in the lowest bit
+        let little_endian = poly.to_ordered(VariableOrder::LittleEndian);
+        assert_eq!(little_endian[0b001], poly.eval_form[0b100]);
+        assert_eq!(little_endian[0b011], poly.eval_form[0b110]);
+        let restored =
+            MultilinearEvalForm::from_ordered(little_endian.clone(), VariableOrder::LittleEndian);
+        assert_eq!(restored.eval_form, poly.eval_form);
+        assert_eq!(poly.to_ordered(VariableOrder::BigEndian), poly.eval_form);
+
+        // the little endian table read as big endian is f with its variables reversed
+        let reversed = MultilinearEvalForm::new(little_endian);
+        assert_eq!(
+            reversed.eval_form,
+            poly.permute_variables(&[3, 2, 1]).eval_form
+        );
+        assert_eq!(reversed.evaluate(&[point[2], point[1], point[0]]), expected);
+
+        // every permutation of (a, b, c), with the point permuted the same way
+        let permutations = [
+            [1, 2, 3],
+            [1, 3, 2],
+            [2, 1, 3],
+            [2, 3, 1],
+            [3, 1, 2],
+            [3, 2, 1],
+        ];
+        for permutation in permutations {
+            let permuted = poly.permute_variables(&permutation);
+            let permuted_point: Vec<Fq> = permutation
+                .iter()
+                .map(|position| point[*position as usize - 1])
+                .collect();
+            assert_eq!(permuted.evaluate(&permuted_point), expected);
+
+            // folding the permuted polynomial from either end
+            let mut high = permuted.clone();
+            high.fold_high(permuted_point[0]);
+            assert_eq!(high.evaluate(&permuted_point[1..]), expected);
+            let mut low = permuted.clone();
+            low.fold_low(permuted_point[2]);
+            assert_eq!(low.evaluate(&permuted_point[..2]), expected);
+        }
+    }
+
+    #[test]
+    fn test_from_padded_and_from_fn() {
+        let padded =
+            MultilinearEvalForm::from_padded(convert_to_fq_elements(vec![1, 2, 3]), Fq::from(0));
+        assert_eq!(padded.number_of_variables, 2);
+        assert_eq!(padded.eval_form, convert_to_fq_elements(vec![1, 2, 3, 0]));
+        let exact =
+            MultilinearEvalForm::from_padded(convert_to_fq_elements(vec![1, 2]), Fq::from(9));
+        assert_eq!(exact.eval_form, convert_to_fq_elements(vec![1, 2]));
+        let empty = MultilinearEvalForm::from_padded(Vec::new(), Fq::from(9));
+        assert_eq!(empty.eval_form, vec![Fq::from(9)]);
+
+        let poly = MultilinearEvalForm::from_fn(3, |index| Fq::from(index as u64 * 2));
+        assert_eq!(
+            poly.eval_form,
+            convert_to_fq_elements(vec![0, 2, 4, 6, 8, 10, 12, 14])
+        );
+    }
+
+    #[test]
+    fn test_extend_variables() {
+        // f(a, b) embedded as g(a, x, b)
+        let poly = MultilinearEvalForm::new(convert_to_fq_elements(vec![1, 2, 3, 4]));
+        let extended = poly.extend_variables(3, &[1, 3]);
+        assert_eq!(
+            extended.eval_form,
+            convert_to_fq_elements(vec![1, 2, 1, 2, 3, 4, 3, 4])
+        );
+        let point = gen_random_vars::<Fq>(3);
+        assert_eq!(
+            extended.evaluate(&point),
+            poly.evaluate(&[point[0], point[2]])
+        );
+
+        // positions in any order also permute the variables
+        let swapped = poly.extend_variables(3, &[3, 2]);
+        assert_eq!(
+            swapped.evaluate(&point),
+            poly.evaluate(&[point[2], point[1]])
+        );
+    }
+
+    #[test]
+    fn test_restrict() {
+        let poly = MultilinearEvalForm::new(gen_based_on_two::<Fq>(3));
+        // b = 1 -> g(a, c)
+        let restricted = poly.restrict(&[(2, true)]);
+        assert_eq!(restricted.number_of_variables, 2);
+        assert_eq!(
+            restricted.eval_form,
+            vec![
+                poly.eval_form[0b010],
+                poly.eval_form[0b011],
+                poly.eval_form[0b110],
+                poly.eval_form[0b111]
+            ]
+        );
+        let point = gen_random_vars::<Fq>(2);
+        assert_eq!(
+            restricted.evaluate(&point),
+            poly.evaluate(&[point[0], Fq::from(1), point[1]])
+        );
+
+        // restricting the variables an extension added gives the polynomial back
+        let extended = poly.extend_variables(5, &[2, 3, 5]);
+        assert_eq!(
+            extended.restrict(&[(4, true), (1, false)]).eval_form,
+            poly.eval_form
+        );
+        assert_eq!(poly.restrict(&[]).eval_form, poly.eval_form);
+    }
+
+    #[test]
+    fn test_partial_evaluate_out_of_order_matches_restrict() {
+        let poly = MultilinearEvalForm::new(gen_based_on_two::<Fq>(4));
+        // fix d = 1 and then b = 0, which is still the second of (a, b, c)
+        let mut partial = poly.clone();
+        partial.partial_evaluate(4, Fq::from(1));
+        partial.partial_evaluate(2, Fq::from(0));
+        assert_eq!(
+            partial.eval_form,
+            poly.restrict(&[(4, true), (2, false)]).eval_form
+        );
+
+        // every order of fixing three of the variables at random values, a variable's position
+        // drops by one for each variable before it that is already fixed
+        let point = gen_random_vars::<Fq>(4);
+        let expected = poly.evaluate(&point);
+        for order in [[1, 2, 3], [3, 2, 1], [2, 4, 1], [4, 1, 3], [3, 4, 2]] {
+            let mut partial = poly.clone();
+            for (i, original) in order.iter().enumerate() {
+                let fixed_before = order[..i].iter().filter(|fixed| *fixed < original).count();
+                let position = original - fixed_before as u32;
+                partial.partial_evaluate(position, point[*original as usize - 1]);
+            }
+            let last = (1..=4).find(|position| !order.contains(position)).unwrap();
+            assert_eq!(partial.evaluate(&[point[last as usize - 1]]), expected);
+        }
+    }
+
+    #[test]
+    #[should_panic]
+    fn test_permute_variables_repeated() {
+        MultilinearEvalForm::new(gen_based_on_two::<Fq>(2)).permute_variables(&[1, 1]);
+    }
+
+    #[test]
+    fn test_partial_evaluate_then_evaluate_and_fold() {
+        let values = gen_based_on_two::<Fq>(4);
+        let poly = MultilinearEvalForm::new(values.clone());
+        let point = gen_random_vars::<Fq>(4);
+        let expected = poly.evaluate(&point);
+
+        // fix b, the rest is a polynomial in (a, c, d)
+        let mut partial = poly.clone();
+        partial.partial_evaluate(2, point[1]);
+        assert_eq!(partial.number_of_variables, 3);
+        assert_eq!(partial.evaluate(&[point[0], point[2], point[3]]), expected);
+        assert_eq!(
+            partial.fix_variables(&[point[0]]).evaluate(&point[2..]),
+            expected
+        );
+
+        // fold a, then fix d, the second of (c, d)
+        partial.fold(point[0]);
+        assert_eq!(partial.number_of_variables, 2);
+        partial.partial_evaluate(2, point[3]);
+        assert_eq!(partial.evaluate(&[point[2]]), expected);
+        partial.fold_low(point[2]);
+        assert_eq!(partial.eval_form, vec![expected]);
+
+        // the same through a product poly
+        let mut prod_poly = ProdPoly::new(vec![poly.clone(), poly.clone()]);
+        prod_poly.partial_evaluate(3, point[2]);
+        assert_eq!(prod_poly.no_of_vars, 3);
+        assert_eq!(
+            prod_poly.evaluate(&[point[0], point[1], point[3]]),
+            expected * expected
+        );
+    }
+
+    #[test]
+    #[should_panic]
+    fn test_fold_constant() {
+        let mut poly = MultilinearEvalForm::new(vec![Fq::from(4)]);
+        poly.fold(Fq::from(1));
+    }
+
+    #[test]
+    fn test_fix_variables_of_sum_poly() {
+        let sum_poly = get_sum_of_prod_poly();
+        let point = [Fq::from(1), Fq::from(2)];
+        let fixed = sum_poly.fix_variables(&point[..1]);
+        assert_eq!(fixed.get_no_of_vars(), 1);
+        assert_eq!(fixed.evaluate(&point[1..]), sum_poly.evaluate(&point));
+
+        let mut folded = sum_poly.clone();
+        folded.fold(point[0]);
+        folded.fold(point[1]);
+        assert_eq!(folded.reduce(), vec![Fq::from(48)]);
+        // the original is untouched
+        assert_eq!(sum_poly.evaluate(&point), Fq::from(48));
+    }
+
     #[test]
     fn test_to_bytes_for_2vars() {
         let polynomial =
@@ -473,7 +926,7 @@ pub mod tests {
     }
     #[test]
     fn test_evaluate_for_3vars() {
-        let mut eval_form = MultilinearEvalForm::new(vec![
+        let eval_form = MultilinearEvalForm::new(vec![
             Fq::from(0),
             Fq::from(0),
             Fq::from(0),
@@ -484,7 +937,7 @@ pub mod tests {
             Fq::from(5),
         ]);
         assert_eq!(
-            eval_form.evaluate(&vec![Fq::from(4), Fq::from(2), Fq::from(3)]),
+            eval_form.evaluate(&[Fq::from(4), Fq::from(2), Fq::from(3)]),
             Fq::from(34)
         );
     }
@@ -515,17 +968,11 @@ pub mod tests {
 
     #[test]
     fn test_prod_poly_eval() {
-        let mut prod_poly = get_prod_poly();
-        let eval = prod_poly.evaluate(&vec![Fq::from(1), Fq::from(2)]);
+        let prod_poly = get_prod_poly();
+        let eval = prod_poly.evaluate(&[Fq::from(1), Fq::from(2)]);
         assert_eq!(eval, Fq::from(24));
     }
 
-    #[test]
-    fn test_combine_convert() {
-        assert_eq!(combine_convert(vec![1, 2, 3], 3), 83);
-        assert_eq!(combine_convert(vec![1, 0, 2], 2), 18);
-    }
-
     #[test]
     fn tensor_add() {
         let w_b =
@@ -550,10 +997,57 @@ pub mod tests {
         );
     }
 
+    #[test]
+    fn test_tensor_different_number_of_variables() {
+        // f(a, b) and g(c), h(a, b, c) = f(a, b) + g(c)
+        let f = MultilinearEvalForm::new(convert_to_fq_elements(vec![1, 2, 3, 4]));
+        let g = MultilinearEvalForm::new(convert_to_fq_elements(vec![10, 20]));
+        let sum = MultilinearEvalForm::tensor_add(&f, &g);
+        assert_eq!(sum.number_of_variables, 3);
+        assert_eq!(
+            sum.eval_form,
+            convert_to_fq_elements(vec![11, 21, 12, 22, 13, 23, 14, 24])
+        );
+        let point = gen_random_vars::<Fq>(3);
+        assert_eq!(
+            MultilinearEvalForm::tensor_mul(&g, &f).evaluate(&point),
+            g.evaluate(&point[..1]) * f.evaluate(&point[1..])
+        );
+    }
+
+    #[test]
+    fn test_tensor_of_many_polynomials() {
+        let f = MultilinearEvalForm::new(gen_based_on_two::<Fq>(3));
+        let g = MultilinearEvalForm::new(gen_based_on_two::<Fq>(1));
+        let h = MultilinearEvalForm::new(gen_based_on_two::<Fq>(2));
+        let product = MultilinearEvalForm::tensor(&[&f, &g, &h], |a, b| a * b);
+        assert_eq!(product.number_of_variables, 6);
+        let point = gen_random_vars::<Fq>(6);
+        assert_eq!(
+            product.evaluate(&point),
+            f.evaluate(&point[..3]) * g.evaluate(&point[3..4]) * h.evaluate(&point[4..])
+        );
+
+        // any binary op, e.g f(x) - g(y)
+        let difference = MultilinearEvalForm::tensor(&[&f, &g], |a, b| a - b);
+        assert_eq!(
+            difference.eval_form[0b1011],
+            f.eval_form[0b101] - g.eval_form[0b1]
+        );
+        let single = MultilinearEvalForm::tensor(&[&f], |a, b| a * b);
+        assert_eq!(single.eval_form, f.eval_form);
+    }
+
+    #[test]
+    #[should_panic]
+    fn test_tensor_of_no_polynomials() {
+        MultilinearEvalForm::<Fq>::tensor(&[], |a, b| a * b);
+    }
+
     #[test]
     fn test_sum_of_prod_poly_eval() {
-        let mut sum_poly = get_sum_of_prod_poly();
-        let eval = sum_poly.evaluate(&vec![Fq::from(1), Fq::from(2)]);
+        let sum_poly = get_sum_of_prod_poly();
+        let eval = sum_poly.evaluate(&[Fq::from(1), Fq::from(2)]);
         assert_eq!(eval, Fq::from(48));
     }
 
@@ -0,0 +1,528 @@
+use crate::evaluation_form::MultilinearEvalForm;
+use crate::hypercube::pairs;
+use crate::parallel::cfg_into_iter;
+use ark_ff::{BigInteger, PrimeField};
+#[cfg(feature = "parallel")]
+use rayon::prelude::*;
+use std::collections::BTreeMap;
+use std::ops::{Add, Mul, Neg, Sub};
+use std::sync::Arc;
+
+// ==============================================================//
+//    @note Virtual Poly
+// =============================================================//
+
+// a polynomial built out of multilinear polys without multiplying them out, e.g 3·f·g - h + 5 or f^3
+// every term is (coefficient, indices) and stands for coefficient * mles[i] * mles[j] * ...
+// an index can repeat, so f^3 is (1, [0, 0, 0]), and a term with no indices is a constant
+// the mles are shared, so combining virtual polys that use the same f stores f once
+#[derive(Clone)]
+pub struct VirtualPolynomial<F: PrimeField> {
+    pub number_of_variables: u32,
+    pub mles: Vec<Arc<MultilinearEvalForm<F>>>,
+    pub terms: Vec<(F, Vec<usize>)>,
+    // the number of factors of the largest term, a bound on the degree in every variable and so on
+    // the degree of a sumcheck round polynomial, degrees() gives the exact degree of each variable
+    pub max_degree: usize,
+}
+
+impl<F: PrimeField> VirtualPolynomial<F> {
+    // the zero polynomial over number_of_variables variables
+    pub fn new(number_of_variables: u32) -> Self {
+        Self {
+            number_of_variables,
+            mles: Vec::new(),
+            terms: Vec::new(),
+            max_degree: 0,
+        }
+    }
+
+    pub fn from_mle(mle: Arc<MultilinearEvalForm<F>>) -> Self {
+        let mut polynomial = Self::new(mle.number_of_variables);
+        polynomial.add_term(F::one(), &[mle]);
+        polynomial
+    }
+
+    pub fn constant(number_of_variables: u32, value: F) -> Self {
+        let mut polynomial = Self::new(number_of_variables);
+        polynomial.add_term(value, &[]);
+        polynomial
+    }
+
+    // adds coefficient * factors[0] * factors[1] * ...
+    pub fn add_term(&mut self, coefficient: F, factors: &[Arc<MultilinearEvalForm<F>>]) {
+        let indices = factors.iter().map(|mle| self.mle_index(mle)).collect();
+        self.terms.push((coefficient, indices));
+        self.normalise();
+    }
+
+    pub fn is_zero(&self) -> bool {
+        self.terms.is_empty()
+    }
+
+    pub fn scalar_mul(&self, scalar: F) -> Self {
+        let mut polynomial = self.clone();
+        for (coefficient, _) in &mut polynomial.terms {
+            *coefficient *= scalar;
+        }
+        polynomial.normalise();
+        polynomial
+    }
+
+    pub fn add_scalar(&self, scalar: F) -> Self {
+        let mut polynomial = self.clone();
+        polynomial.terms.push((scalar, Vec::new()));
+        polynomial.normalise();
+        polynomial
+    }
+
+    // every mle is evaluated once, however many terms use it
+    pub fn evaluate(&self, variables: &[F]) -> F {
+        if variables.len() != self.number_of_variables as usize {
+            panic!("Invalid number of points")
+        }
+        let evaluations: Vec<F> = self
+            .mles
+            .iter()
+            .map(|mle| mle.evaluate(variables))
+            .collect();
+        self.terms
+            .iter()
+            .map(|(coefficient, indices)| {
+                indices
+                    .iter()
+                    .fold(*coefficient, |product, i| product * evaluations[*i])
+            })
+            .sum()
+    }
+
+    // the same as MultilinearEvalForm::partial_evaluate for every mle, positions count the variables
+    // that are left, so mles added after an earlier call are fixed in the same variable
+    // an mle that is shared with another polynomial is copied first
+    pub fn partial_evaluate(&mut self, variable_position: u32, value: F) {
+        if variable_position == 0 || variable_position > self.number_of_variables {
+            panic!("Number of variables Exceeded!")
+        }
+        for mle in &mut self.mles {
+            Arc::make_mut(mle).partial_evaluate(variable_position, value);
+        }
+        self.number_of_variables -= 1;
+    }
+
+    // fixes the first values.len() variables of every mle
+    pub fn fix_variables(&self, values: &[F]) -> Self {
+        let mles: Vec<Arc<MultilinearEvalForm<F>>> = self
+            .mles
+            .iter()
+            .map(|mle| Arc::new(mle.fix_variables(values)))
+            .collect();
+        Self {
+            number_of_variables: self.number_of_variables - values.len() as u32,
+            mles,
+            terms: self.terms.clone(),
+            max_degree: self.max_degree,
+        }
+    }
+
+    // fixes the first remaining variable of every mle in place
+    pub fn fold(&mut self, value: F) {
+        if self.number_of_variables == 0 {
+            panic!("no variables left to fold");
+        }
+        for mle in &mut self.mles {
+            Arc::make_mut(mle).fold(value);
+        }
+        self.number_of_variables -= 1;
+    }
+
+    // the degree in each variable, in order
+    // max_degree bounds all of them, but a factor that ignores a variable, e.g an mle built with
+    // extend_variables, adds nothing to that variable's degree
+    pub fn degrees(&self) -> Vec<usize> {
+        let n = self.number_of_variables;
+        let depends_on: Vec<Vec<bool>> = self
+            .mles
+            .iter()
+            .map(|mle| {
+                (1..=n)
+                    .map(|position| {
+                        pairs(n, position)
+                            .any(|(low, high)| mle.eval_form[low] != mle.eval_form[high])
+                    })
+                    .collect()
+            })
+            .collect();
+        (0..n as usize)
+            .map(|variable| {
+                self.terms
+                    .iter()
+                    .map(|(_, indices)| {
+                        indices.iter().filter(|i| depends_on[**i][variable]).count()
+                    })
+                    .max()
+                    .unwrap_or(0)
+            })
+            .collect()
+    }
+
+    // this function reduces the virtual poly to a simple boolean hypercube representation in evaluation form
+    pub fn reduce(&self) -> Vec<F> {
+        cfg_into_iter!(0..1usize << self.number_of_variables)
+            .map(|x| {
+                self.terms
+                    .iter()
+                    .map(|(coefficient, indices)| {
+                        indices.iter().fold(*coefficient, |product, i| {
+                            product * self.mles[*i].eval_form[x]
+                        })
+                    })
+                    .sum()
+            })
+            .collect()
+    }
+
+    // the coefficients and factor indices of every term followed by every mle
+    pub fn to_bytes(&self) -> Vec<u8> {
+        let mut bytes = Vec::new();
+        for (coefficient, indices) in &self.terms {
+            bytes.extend(coefficient.into_bigint().to_bytes_be());
+            bytes.extend((indices.len() as u64).to_be_bytes());
+            for i in indices {
+                bytes.extend((*i as u64).to_be_bytes());
+            }
+        }
+        for mle in &self.mles {
+            bytes.extend(MultilinearEvalForm::to_bytes(&mle.eval_form));
+        }
+        bytes
+    }
+
+    // the index of mle in self.mles, added if it is not there yet
+    fn mle_index(&mut self, mle: &Arc<MultilinearEvalForm<F>>) -> usize {
+        if mle.number_of_variables != self.number_of_variables {
+            panic!("Polynomials must be of the same number of variables");
+        }
+        match self.mles.iter().position(|known| Arc::ptr_eq(known, mle)) {
+            Some(index) => index,
+            None => {
+                self.mles.push(mle.clone());
+                self.mles.len() - 1
+            }
+        }
+    }
+
+    // the terms of other, with its mles merged into self.mles
+    fn import_terms(&mut self, other: &VirtualPolynomial<F>) -> Vec<(F, Vec<usize>)> {
+        if self.number_of_variables != other.number_of_variables {
+            panic!("Polynomials must be of the same number of variables");
+        }
+        let indices: Vec<usize> = other.mles.iter().map(|mle| self.mle_index(mle)).collect();
+        other
+            .terms
+            .iter()
+            .map(|(coefficient, factors)| {
+                (*coefficient, factors.iter().map(|i| indices[*i]).collect())
+            })
+            .collect()
+    }
+
+    // sorts the indices of every term, merges equal terms and drops the zero ones
+    fn normalise(&mut self) {
+        let mut merged: BTreeMap<Vec<usize>, F> = BTreeMap::new();
+        for (coefficient, mut indices) in self.terms.drain(..) {
+            indices.sort_unstable();
+            *merged.entry(indices).or_insert(F::zero()) += coefficient;
+        }
+        self.terms = merged
+            .into_iter()
+            .filter(|(_, coefficient)| !coefficient.is_zero())
+            .map(|(indices, coefficient)| (coefficient, indices))
+            .collect();
+        self.max_degree = self
+            .terms
+            .iter()
+            .map(|(_, indices)| indices.len())
+            .max()
+            .unwrap_or(0);
+    }
+}
+
+impl<F: PrimeField> Add for &VirtualPolynomial<F> {
+    type Output = VirtualPolynomial<F>;
+
+    fn add(self, other: &VirtualPolynomial<F>) -> VirtualPolynomial<F> {
+        let mut sum = self.clone();
+        let terms = sum.import_terms(other);
+        sum.terms.extend(terms);
+        sum.normalise();
+        sum
+    }
+}
+
+impl<F: PrimeField> Sub for &VirtualPolynomial<F> {
+    type Output = VirtualPolynomial<F>;
+
+    fn sub(self, other: &VirtualPolynomial<F>) -> VirtualPolynomial<F> {
+        self + &-other
+    }
+}
+
+impl<F: PrimeField> Mul for &VirtualPolynomial<F> {
+    type Output = VirtualPolynomial<F>;
+
+    fn mul(self, other: &VirtualPolynomial<F>) -> VirtualPolynomial<F> {
+        let mut product = self.clone();
+        let other_terms = product.import_terms(other);
+        product.terms = self
+            .terms
+            .iter()
+            .flat_map(|(a, left)| {
+                other_terms.iter().map(move |(b, right)| {
+                    (*a * b, left.iter().chain(right.iter()).copied().collect())
+                })
+            })
+            .collect();
+        product.normalise();
+        product
+    }
+}
+
+impl<F: PrimeField> Neg for &VirtualPolynomial<F> {
+    type Output = VirtualPolynomial<F>;
+
+    fn neg(self) -> VirtualPolynomial<F> {
+        self.scalar_mul(-F::one())
+    }
+}
+
+impl<F: PrimeField> Add for VirtualPolynomial<F> {
+    type Output = VirtualPolynomial<F>;
+
+    fn add(self, other: VirtualPolynomial<F>) -> VirtualPolynomial<F> {
+        &self + &other
+    }
+}
+
+impl<F: PrimeField> Sub for VirtualPolynomial<F> {
+    type Output = VirtualPolynomial<F>;
+
+    fn sub(self, other: VirtualPolynomial<F>) -> VirtualPolynomial<F> {
+        &self - &other
+    }
+}
+
+impl<F: PrimeField> Mul for VirtualPolynomial<F> {
+    type Output = VirtualPolynomial<F>;
+
+    fn mul(self, other: VirtualPolynomial<F>) -> VirtualPolynomial<F> {
+        &self * &other
+    }
+}
+
+impl<F: PrimeField> Neg for VirtualPolynomial<F> {
+    type Output = VirtualPolynomial<F>;
+
+    fn neg(self) -> VirtualPolynomial<F> {
+        -&self
+    }
+}
+
+// scalar ops, e.g f * 3 + 5
+impl<F: PrimeField> Add<F> for VirtualPolynomial<F> {
+    type Output = VirtualPolynomial<F>;
+
+    fn add(self, scalar: F) -> VirtualPolynomial<F> {
+        self.add_scalar(scalar)
+    }
+}
+
+impl<F: PrimeField> Mul<F> for VirtualPolynomial<F> {
+    type Output = VirtualPolynomial<F>;
+
+    fn mul(self, scalar: F) -> VirtualPolynomial<F> {
+        self.scalar_mul(scalar)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::evaluation_form::{gen_based_on_two, gen_random_vars, ProdPoly, SumPoly};
+    use ark_bn254::Fq;
+
+    fn random_mle(n: u32) -> Arc<MultilinearEvalForm<Fq>> {
+        Arc::new(MultilinearEvalForm::new(gen_based_on_two::<Fq>(n)))
+    }
+
+    #[test]
+    fn test_expression() {
+        // 3·f·g - h + 5
+        let (f, g, h) = (random_mle(3), random_mle(3), random_mle(3));
+        let virtual_f = VirtualPolynomial::from_mle(f.clone());
+        let virtual_g = VirtualPolynomial::from_mle(g.clone());
+        let virtual_h = VirtualPolynomial::from_mle(h.clone());
+        let expression = (virtual_f * virtual_g) * Fq::from(3) - virtual_h + Fq::from(5);
+        assert_eq!(expression.mles.len(), 3);
+        assert_eq!(expression.terms.len(), 3);
+        assert_eq!(expression.max_degree, 2);
+
+        let point = gen_random_vars::<Fq>(3);
+        assert_eq!(
+            expression.evaluate(&point),
+            Fq::from(3) * f.evaluate(&point) * g.evaluate(&point) - h.evaluate(&point)
+                + Fq::from(5)
+        );
+        for (x, value) in expression.reduce().iter().enumerate() {
+            assert_eq!(
+                *value,
+                Fq::from(3) * f.eval_form[x] * g.eval_form[x] - h.eval_form[x] + Fq::from(5)
+            );
+        }
+    }
+
+    #[test]
+    fn test_power_shares_the_mle() {
+        let f = random_mle(2);
+        let virtual_f = VirtualPolynomial::from_mle(f.clone());
+        let cube = &(&virtual_f * &virtual_f) * &virtual_f;
+        assert_eq!(cube.mles.len(), 1);
+        assert_eq!(cube.terms, vec![(Fq::from(1), vec![0, 0, 0])]);
+        assert_eq!(cube.max_degree, 3);
+        let point = gen_random_vars::<Fq>(2);
+        let value = f.evaluate(&point);
+        assert_eq!(cube.evaluate(&point), value * value * value);
+    }
+
+    #[test]
+    fn test_terms_cancel() {
+        let f = VirtualPolynomial::from_mle(random_mle(2));
+        let g = VirtualPolynomial::from_mle(random_mle(2));
+        let product = &f * &g;
+        // f·g - g·f is zero, the factors are sorted before merging
+        let zero = &product - &(&g * &f);
+        assert!(zero.is_zero());
+        assert_eq!(zero.max_degree, 0);
+        assert_eq!(zero.reduce(), vec![Fq::from(0); 4]);
+        assert!((VirtualPolynomial::constant(2, Fq::from(4)) + Fq::from(-4)).is_zero());
+    }
+
+    #[test]
+    fn test_matches_sum_poly() {
+        // (f·g) + (g·h) as a SumPoly and as a virtual poly
+        let (f, g, h) = (random_mle(3), random_mle(3), random_mle(3));
+        let sum_poly = SumPoly::new(vec![
+            ProdPoly::new(vec![(*f).clone(), (*g).clone()]),
+            ProdPoly::new(vec![(*g).clone(), (*h).clone()]),
+        ]);
+        let mut virtual_poly = VirtualPolynomial::new(3);
+        virtual_poly.add_term(Fq::from(1), &[f, g.clone()]);
+        virtual_poly.add_term(Fq::from(1), &[g, h]);
+        assert_eq!(virtual_poly.mles.len(), 3);
+        assert_eq!(virtual_poly.reduce(), sum_poly.reduce());
+
+        let point = gen_random_vars::<Fq>(3);
+        assert_eq!(virtual_poly.evaluate(&point), sum_poly.evaluate(&point));
+        let fixed = virtual_poly.fix_variables(&point[..1]);
+        assert_eq!(fixed.number_of_variables, 2);
+        assert_eq!(fixed.reduce(), sum_poly.fix_variables(&point[..1]).reduce());
+        assert_eq!(fixed.evaluate(&point[1..]), virtual_poly.evaluate(&point));
+    }
+
+    #[test]
+    fn test_fold_and_partial_evaluate() {
+        let f = random_mle(3);
+        let virtual_f = VirtualPolynomial::from_mle(f.clone());
+        let square = (&virtual_f * &virtual_f) + Fq::from(1);
+        let point = gen_random_vars::<Fq>(3);
+
+        let mut folded = square.clone();
+        folded.fold(point[0]);
+        folded.fold(point[1]);
+        assert_eq!(folded.number_of_variables, 1);
+        assert_eq!(folded.evaluate(&point[2..]), square.evaluate(&point));
+        // f itself is still shared with the original, untouched
+        assert_eq!(square.mles[0].eval_form, f.eval_form);
+
+        let mut partial = square.clone();
+        partial.partial_evaluate(2, point[1]);
+        assert_eq!(partial.number_of_variables, 2);
+        assert_eq!(
+            partial.evaluate(&[point[0], point[2]]),
+            square.evaluate(&point)
+        );
+        let mut expected = (*f).clone();
+        let values = expected.partial_evaluate(2, point[1]);
+        assert_eq!(
+            partial.reduce(),
+            values
+                .iter()
+                .map(|y| *y * y + Fq::from(1))
+                .collect::<Vec<_>>()
+        );
+    }
+
+    #[test]
+    fn test_partial_evaluate_constant_and_add_term() {
+        // a constant has no tables to shrink, its length still follows number_of_variables
+        let mut constant = VirtualPolynomial::constant(2, Fq::from(4));
+        constant.partial_evaluate(1, Fq::from(3));
+        assert_eq!(constant.number_of_variables, 1);
+        assert_eq!(constant.reduce(), vec![Fq::from(4); 2]);
+
+        let mut poly = VirtualPolynomial::from_mle(random_mle(3)) + Fq::from(4);
+        poly.partial_evaluate(3, Fq::from(3));
+        assert_eq!(poly.reduce().len(), 4);
+        let g = random_mle(2);
+        poly.add_term(Fq::from(2), std::slice::from_ref(&g));
+        let point = gen_random_vars::<Fq>(2);
+        let f = poly.mles[0].clone();
+        assert_eq!(
+            poly.evaluate(&point),
+            f.evaluate(&point) + Fq::from(4) + Fq::from(2) * g.evaluate(&point)
+        );
+
+        // the next call fixes the second variable of f and g alike
+        poly.partial_evaluate(2, point[1]);
+        assert_eq!(
+            poly.evaluate(&point[..1]),
+            f.evaluate(&point) + Fq::from(4) + Fq::from(2) * g.evaluate(&point)
+        );
+    }
+
+    #[test]
+    #[should_panic]
+    fn test_partial_evaluate_constant_past_its_variables() {
+        let mut constant = VirtualPolynomial::constant(2, Fq::from(4));
+        constant.partial_evaluate(3, Fq::from(3));
+    }
+
+    #[test]
+    fn test_degrees() {
+        // f(a, b) * g(c) * g(c) over (a, b, c), extended so each ignores the other's variables
+        let f = random_mle(2);
+        let g = random_mle(1);
+        let f = Arc::new(f.extend_variables(3, &[1, 2]));
+        let g = Arc::new(g.extend_variables(3, &[3]));
+        let mut poly = VirtualPolynomial::new(3);
+        poly.add_term(Fq::from(1), &[f.clone(), g.clone(), g.clone()]);
+        poly.add_term(Fq::from(5), &[f.clone(), f]);
+        assert_eq!(poly.max_degree, 3);
+        assert_eq!(poly.degrees(), vec![2, 2, 2]);
+
+        let mut linear = VirtualPolynomial::new(3);
+        linear.add_term(Fq::from(1), &[g.clone(), g]);
+        assert_eq!(linear.degrees(), vec![0, 0, 2]);
+        assert_eq!(
+            VirtualPolynomial::<Fq>::constant(2, Fq::from(1)).degrees(),
+            vec![0, 0]
+        );
+    }
+
+    #[test]
+    #[should_panic]
+    fn test_different_number_of_variables() {
+        let f = VirtualPolynomial::from_mle(random_mle(2));
+        let g = VirtualPolynomial::from_mle(random_mle(3));
+        let _ = f + g;
+    }
+}
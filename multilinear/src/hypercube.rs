@@ -0,0 +1,274 @@
+// ==============================================================//
+//    @note Boolean hypercube indexing
+// =============================================================//
+
+// a point of the boolean hypercube {0, 1}^n is an index with one bit per variable
+// the first variable is the most significant bit, for f(a,b,c): a -> 0b100, b -> 0b010, c -> 0b001
+// variable positions are 1-based as in partial_evaluate, a is 1, b -> 2, c -> 3
+
+// how the variables of a table of evaluations map to the bits of its index
+// MultilinearEvalForm, sumcheck and GKR all use BigEndian, LittleEndian is for tables that come from
+// code putting the first variable in the lowest bit, they are converted with bit_reverse
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum VariableOrder {
+    // the first variable is the most significant bit, for f(a,b,c): a -> 0b100
+    #[default]
+    BigEndian,
+    // the first variable is the least significant bit, for f(a,b,c): a -> 0b001
+    LittleEndian,
+}
+
+impl VariableOrder {
+    // the big endian index of the point stored at index in this order
+    pub fn to_big_endian(&self, index: usize, number_of_variables: u32) -> usize {
+        match self {
+            VariableOrder::BigEndian => index,
+            VariableOrder::LittleEndian => bit_reverse(index, number_of_variables),
+        }
+    }
+}
+
+// the bit that holds the variable at this position
+pub fn variable_bit(number_of_variables: u32, position: u32) -> u32 {
+    if position == 0 || position > number_of_variables {
+        panic!(
+            "variable {} does not exist over {} variables",
+            position, number_of_variables
+        );
+    }
+    number_of_variables - position
+}
+
+// the value the variable at this position takes at the point
+pub fn variable_value(index: usize, number_of_variables: u32, position: u32) -> bool {
+    index >> variable_bit(number_of_variables, position) & 1 == 1
+}
+
+// the point that differs from index only in the variable at this position
+pub fn neighbour(index: usize, number_of_variables: u32, position: u32) -> usize {
+    index ^ (1 << variable_bit(number_of_variables, position))
+}
+
+// concatenates the indices of groups of variables, the first group is the most significant
+// e.g 0b01 over 2 variables and 0b1 over 1 variable -> 0b011
+pub fn pack(indices: &[usize], widths: &[u32]) -> usize {
+    if indices.len() != widths.len() {
+        panic!("every index needs a width");
+    }
+    check_total_width(widths.iter().sum());
+    indices
+        .iter()
+        .zip(widths)
+        .fold(0, |packed, (index, width)| append(packed, *index, *width))
+}
+
+// splits a packed index back into its groups, the inverse of pack
+pub fn unpack(index: usize, widths: &[u32]) -> Vec<usize> {
+    check_total_width(widths.iter().sum());
+    let mut indices = vec![0; widths.len()];
+    let mut rest = index;
+    for (group, width) in indices.iter_mut().zip(widths).rev() {
+        *group = rest & low_mask(*width);
+        rest = rest.checked_shr(*width).unwrap_or(0);
+    }
+    indices
+}
+
+// packs groups that all have the same width
+// e.g the (output, left, right) wiring of a GKR gate: [1, 2, 3] with width 3 -> 0b001_010_011 = 83
+pub fn combine_convert(values: &[usize], width: u32) -> usize {
+    check_total_width(width * values.len() as u32);
+    values
+        .iter()
+        .fold(0, |packed, value| append(packed, *value, width))
+}
+
+// reverses the order of the variables, 0b110 -> 0b011 over 3 variables
+// only the low number_of_variables bits of index are read
+pub fn bit_reverse(index: usize, number_of_variables: u32) -> usize {
+    check_total_width(number_of_variables);
+    if number_of_variables == 0 {
+        return 0;
+    }
+    index.reverse_bits() >> (usize::BITS - number_of_variables)
+}
+
+// the points where the variable at this position is fixed to value, in increasing order
+// this is the sub-cube over the other n - 1 variables, e.g the half partial_evaluate reads at value
+pub fn sub_cube(
+    number_of_variables: u32,
+    position: u32,
+    value: bool,
+) -> impl Iterator<Item = usize> {
+    let bit = variable_bit(number_of_variables, position);
+    let fixed = usize::from(value) << bit;
+    // the bits above the variable move up by one to make room for it
+    (0..1usize << (number_of_variables - 1)).map(move |i| {
+        let high = (i >> bit).checked_shl(bit + 1).unwrap_or(0);
+        high | fixed | (i & low_mask(bit))
+    })
+}
+
+// every pair of neighbours along the variable at this position, (variable = 0, variable = 1)
+pub fn pairs(number_of_variables: u32, position: u32) -> impl Iterator<Item = (usize, usize)> {
+    let stride = 1usize << variable_bit(number_of_variables, position);
+    sub_cube(number_of_variables, position, false).map(move |low| (low, low | stride))
+}
+
+// moves the bits of a point of a smaller cube into a larger one, the i-th variable of index
+// becomes the variable at positions[i] of the larger cube and the other bits are left clear
+// e.g 0b10 with positions [3, 1] over 3 variables -> 0b001
+pub fn scatter(index: usize, number_of_variables: u32, positions: &[u32]) -> usize {
+    let width = positions.len() as u32;
+    positions
+        .iter()
+        .enumerate()
+        .fold(0, |scattered, (i, position)| {
+            let bit = index >> (width - 1 - i as u32) & 1;
+            scattered | bit << variable_bit(number_of_variables, *position)
+        })
+}
+
+// the inverse of scatter, reads the variables at positions into a point of the smaller cube
+pub fn gather(index: usize, number_of_variables: u32, positions: &[u32]) -> usize {
+    positions.iter().fold(0, |gathered, position| {
+        gathered << 1 | (index >> variable_bit(number_of_variables, *position) & 1)
+    })
+}
+
+// panics unless every position is a variable and no variable appears twice
+pub fn check_positions(number_of_variables: u32, positions: &[u32]) {
+    let mut seen = 0usize;
+    for position in positions {
+        let bit = 1 << variable_bit(number_of_variables, *position);
+        if seen & bit != 0 {
+            panic!("variable {} appears twice", position);
+        }
+        seen |= bit;
+    }
+}
+
+fn append(packed: usize, index: usize, width: u32) -> usize {
+    if index & !low_mask(width) != 0 {
+        panic!("{} does not fit in {} bits", index, width);
+    }
+    packed.checked_shl(width).unwrap_or(0) | index
+}
+
+fn low_mask(width: u32) -> usize {
+    usize::MAX.checked_shr(usize::BITS - width).unwrap_or(0)
+}
+
+fn check_total_width(width: u32) {
+    if width > usize::BITS {
+        panic!("{} variables do not fit in a usize index", width);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_combine_convert() {
+        assert_eq!(combine_convert(&[1, 2, 3], 3), 83);
+        assert_eq!(combine_convert(&[1, 0, 2], 2), 18);
+        assert_eq!(combine_convert(&[], 2), 0);
+    }
+
+    #[test]
+    #[should_panic]
+    fn test_combine_convert_value_too_wide() {
+        combine_convert(&[4, 0], 2);
+    }
+
+    #[test]
+    fn test_pack_and_unpack() {
+        let widths = [2, 1, 3];
+        assert_eq!(pack(&[0b01, 0b1, 0b110], &widths), 0b011110);
+        assert_eq!(unpack(0b011110, &widths), vec![0b01, 0b1, 0b110]);
+        for index in 0..64 {
+            assert_eq!(pack(&unpack(index, &widths), &widths), index);
+        }
+
+        // a single group can take the whole index
+        assert_eq!(pack(&[usize::MAX], &[usize::BITS]), usize::MAX);
+        assert_eq!(unpack(usize::MAX, &[0, usize::BITS]), vec![0, usize::MAX]);
+        // wider than u32, which combine_convert used to be limited to
+        assert_eq!(pack(&[1, 0], &[1, 40]), 1 << 40);
+    }
+
+    #[test]
+    fn test_variables_and_neighbours() {
+        // f(a, b, c) at (1, 1, 0)
+        let index = 0b110;
+        assert_eq!(variable_bit(3, 1), 2);
+        assert!(variable_value(index, 3, 1));
+        assert!(!variable_value(index, 3, 3));
+        assert_eq!(neighbour(index, 3, 1), 0b010);
+        assert_eq!(neighbour(index, 3, 3), 0b111);
+    }
+
+    #[test]
+    #[should_panic]
+    fn test_variable_out_of_range() {
+        variable_bit(3, 4);
+    }
+
+    #[test]
+    fn test_bit_reverse() {
+        assert_eq!(bit_reverse(0b110, 3), 0b011);
+        assert_eq!(bit_reverse(0b0001, 4), 0b1000);
+        assert_eq!(bit_reverse(0, 0), 0);
+        for index in 0..32 {
+            assert_eq!(bit_reverse(bit_reverse(index, 5), 5), index);
+        }
+    }
+
+    #[test]
+    fn test_scatter_and_gather() {
+        assert_eq!(scatter(0b10, 3, &[3, 1]), 0b001);
+        assert_eq!(scatter(0b11, 3, &[3, 1]), 0b101);
+        assert_eq!(gather(0b101, 3, &[3, 1]), 0b11);
+        assert_eq!(gather(0b111, 3, &[]), 0);
+        for index in 0..8 {
+            assert_eq!(gather(scatter(index, 5, &[2, 5, 4]), 5, &[2, 5, 4]), index);
+        }
+        check_positions(3, &[3, 1, 2]);
+    }
+
+    #[test]
+    #[should_panic]
+    fn test_check_positions_repeated() {
+        check_positions(3, &[2, 2]);
+    }
+
+    #[test]
+    fn test_variable_order() {
+        // (a, b, c) = (1, 0, 0)
+        assert_eq!(VariableOrder::BigEndian.to_big_endian(0b100, 3), 0b100);
+        assert_eq!(VariableOrder::LittleEndian.to_big_endian(0b001, 3), 0b100);
+        assert_eq!(VariableOrder::default(), VariableOrder::BigEndian);
+    }
+
+    #[test]
+    fn test_sub_cube_and_pairs() {
+        // b = 1 over (a, b, c)
+        assert_eq!(
+            sub_cube(3, 2, true).collect::<Vec<_>>(),
+            vec![0b010, 0b011, 0b110, 0b111]
+        );
+        assert_eq!(sub_cube(3, 1, false).collect::<Vec<_>>(), vec![0, 1, 2, 3]);
+        assert_eq!(sub_cube(1, 1, true).collect::<Vec<_>>(), vec![1]);
+        assert_eq!(
+            pairs(3, 3).collect::<Vec<_>>(),
+            vec![(0, 1), (2, 3), (4, 5), (6, 7)]
+        );
+        for position in 1..=4 {
+            for (low, high) in pairs(4, position) {
+                assert_eq!(neighbour(low, 4, position), high);
+                assert!(!variable_value(low, 4, position));
+            }
+        }
+    }
+}
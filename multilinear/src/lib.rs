@@ -1,2 +1,6 @@
 pub mod coefficient_form;
 pub mod evaluation_form;
+pub mod hypercube;
+mod parallel;
+pub mod parse;
+pub mod serialization;
@@ -0,0 +1,130 @@
+// ==============================================================//
+//    @note helpers for the `parallel` feature
+// =============================================================//
+
+// splits the hypercube tables of folds, transforms, tensors and reduce across threads
+
+macro_rules! cfg_iter {
+    ($e:expr) => {{
+        #[cfg(feature = "parallel")]
+        let result = $e.par_iter();
+        #[cfg(not(feature = "parallel"))]
+        let result = $e.iter();
+        result
+    }};
+}
+pub(crate) use cfg_iter;
+
+macro_rules! cfg_into_iter {
+    ($e:expr) => {{
+        #[cfg(feature = "parallel")]
+        let result = $e.into_par_iter();
+        #[cfg(not(feature = "parallel"))]
+        let result = $e.into_iter();
+        result
+    }};
+}
+pub(crate) use cfg_into_iter;
+
+macro_rules! cfg_iter_mut {
+    ($e:expr) => {{
+        #[cfg(feature = "parallel")]
+        let result = $e.par_iter_mut();
+        #[cfg(not(feature = "parallel"))]
+        let result = $e.iter_mut();
+        result
+    }};
+}
+pub(crate) use cfg_iter_mut;
+
+macro_rules! cfg_chunks_mut {
+    ($e:expr, $size:expr) => {{
+        #[cfg(feature = "parallel")]
+        let result = $e.par_chunks_mut($size);
+        #[cfg(not(feature = "parallel"))]
+        let result = $e.chunks_mut($size);
+        result
+    }};
+}
+pub(crate) use cfg_chunks_mut;
+
+#[cfg(all(test, feature = "parallel"))]
+mod tests {
+    use crate::coefficient_form::dense_multilinear::{mobius_transform, zeta_transform};
+    use crate::evaluation_form::{
+        gen_based_on_two, gen_random_vars, MultilinearEvalForm, ProdPoly, SumPoly,
+    };
+    use ark_bn254::Fq;
+
+    #[test]
+    fn test_folds_match_serial() {
+        let values = gen_based_on_two::<Fq>(10);
+        let r = gen_random_vars::<Fq>(1)[0];
+        for position in [1, 5, 10] {
+            let stride = 1 << (10 - position);
+            let mut expected = Vec::new();
+            for i in 0..values.len() {
+                if i & stride == 0 {
+                    expected.push(values[i] + r * (values[i | stride] - values[i]));
+                }
+            }
+            let mut poly = MultilinearEvalForm::new(values.clone());
+            assert_eq!(poly.partial_evaluate(position, r), expected);
+            if position == 1 {
+                let mut folded = MultilinearEvalForm::new(values.clone());
+                folded.fold(r);
+                assert_eq!(folded.eval_form, expected);
+            }
+        }
+    }
+
+    #[test]
+    fn test_reduce_matches_serial() {
+        let (f, g) = (gen_based_on_two::<Fq>(10), gen_based_on_two::<Fq>(10));
+        let prod_poly = ProdPoly::new(vec![
+            MultilinearEvalForm::new(f.clone()),
+            MultilinearEvalForm::new(g.clone()),
+        ]);
+        let sum_poly = SumPoly::new(vec![prod_poly.clone(), prod_poly.clone()]);
+        let mut expected = Vec::new();
+        for i in 0..f.len() {
+            expected.push(f[i] * g[i]);
+        }
+        assert_eq!(prod_poly.reduce(), expected);
+        let doubled: Vec<Fq> = expected.iter().map(|y| *y + y).collect();
+        assert_eq!(sum_poly.reduce(), doubled);
+    }
+
+    #[test]
+    fn test_transforms_and_tensor_match_serial() {
+        let values = gen_based_on_two::<Fq>(8);
+        // the coefficient of a monomial is the alternating sum over the points below it
+        let mut expected = vec![Fq::from(0); values.len()];
+        for (monomial, coefficient) in expected.iter_mut().enumerate() {
+            for (point, value) in values.iter().enumerate() {
+                if point & !monomial == 0 {
+                    let sign = (monomial ^ point).count_ones();
+                    *coefficient += if sign % 2 == 0 { *value } else { -*value };
+                }
+            }
+        }
+        let mut transformed = values.clone();
+        mobius_transform(&mut transformed);
+        assert_eq!(transformed, expected);
+        zeta_transform(&mut transformed);
+        assert_eq!(transformed, values);
+
+        let (f, g) = (gen_based_on_two::<Fq>(3), gen_based_on_two::<Fq>(5));
+        let mut expected = Vec::new();
+        for a in &f {
+            for b in &g {
+                expected.push(*a * b);
+            }
+        }
+        let tensor = MultilinearEvalForm::tensor_mul(
+            &MultilinearEvalForm::new(f),
+            &MultilinearEvalForm::new(g),
+        );
+        assert_eq!(tensor.eval_form, expected);
+    }
+}
@@ -0,0 +1,633 @@
+use crate::coefficient_form::dense_multilinear::DenseMultilinear;
+use crate::coefficient_form::sparse_multilinear::{SparseMultilinear, MAX_SPARSE_VARIABLES};
+use crate::evaluation_form::MultilinearEvalForm;
+use ark_ff::PrimeField;
+use std::fmt;
+use std::str::FromStr;
+
+// ==============================================================//
+//    @note human readable form: "f(a, b, c) = 3ab - 2c + 1"
+// =============================================================//
+
+// the header "f(a, b, c) =" names the variables in order, so the number of variables survives a
+// round trip even when the polynomial does not depend on some of them
+// without a header, variables are a, b, c, ... or x_1, x_2, ... and their count is inferred
+// the letters used must then be a leading run a, b, c, ... so "x + y" asks for a header instead
+// of being read as a polynomial in 25 variables
+
+// dense and evaluation form polynomials are expanded into 2^n values, so n is capped at 20,
+// the same 2^20 values univariate's MAX_EXPONENT allows
+pub const MAX_PARSED_VARIABLES: usize = 20;
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum ParsePolynomialError {
+    // the input has no terms at all
+    Empty,
+    // a character that cannot appear at this position (0-based character index)
+    UnexpectedCharacter { position: usize, found: char },
+    // the input stopped in the middle of a term, e.g "3ab +"
+    UnexpectedEnd,
+    // a coefficient that is not a valid field element
+    InvalidCoefficient(String),
+    // a variable that is not declared in the header
+    UnknownVariable(String),
+    // a variable appearing twice in the header or in one term, the polynomial must be multilinear
+    RepeatedVariable(String),
+    // more than MAX_PARSED_VARIABLES variables for a dense polynomial, or more than
+    // MAX_SPARSE_VARIABLES for a sparse one
+    TooManyVariables(usize),
+}
+
+impl fmt::Display for ParsePolynomialError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            ParsePolynomialError::Empty => write!(f, "empty polynomial"),
+            ParsePolynomialError::UnexpectedCharacter { position, found } => {
+                write!(
+                    f,
+                    "unexpected character '{}' at position {}",
+                    found, position
+                )
+            }
+            ParsePolynomialError::UnexpectedEnd => write!(f, "unexpected end of input"),
+            ParsePolynomialError::InvalidCoefficient(coefficient) => {
+                write!(f, "invalid coefficient '{}'", coefficient)
+            }
+            ParsePolynomialError::UnknownVariable(name) => {
+                write!(f, "unknown variable '{}'", name)
+            }
+            ParsePolynomialError::RepeatedVariable(name) => {
+                write!(f, "variable '{}' appears more than once", name)
+            }
+            ParsePolynomialError::TooManyVariables(count) => {
+                write!(f, "too many variables ({})", count)
+            }
+        }
+    }
+}
+
+impl std::error::Error for ParsePolynomialError {}
+
+// variables are named a, b, c, ... in order, or x_1, x_2, ... when there are more than 26
+pub fn variable_name(index: usize, number_of_variables: usize) -> String {
+    if number_of_variables <= 26 {
+        ((b'a' + index as u8) as char).to_string()
+    } else {
+        format!("x_{}", index + 1)
+    }
+}
+
+// the inverse of variable_name when no header is given
+fn default_variable_index(name: &str) -> Option<usize> {
+    match name.strip_prefix("x_") {
+        Some(index) => index
+            .parse::<usize>()
+            .ok()
+            .filter(|i| *i > 0)
+            .map(|i| i - 1),
+        None if name.len() == 1 => Some((name.as_bytes()[0] - b'a') as usize),
+        None => None,
+    }
+}
+
+// without a header the single letter variables must be a, b, c, ... with none skipped,
+// otherwise the first letter out of place is reported as unknown
+fn check_letter_prefix<'a>(
+    names: impl Iterator<Item = &'a String>,
+) -> Result<(), ParsePolynomialError> {
+    let mut letters: Vec<&String> = names.filter(|name| name.len() == 1).collect();
+    letters.sort();
+    letters.dedup();
+    for (i, letter) in letters.into_iter().enumerate() {
+        if default_variable_index(letter) != Some(i) {
+            return Err(ParsePolynomialError::UnknownVariable(letter.clone()));
+        }
+    }
+    Ok(())
+}
+
+// elements above (p - 1) / 2 are printed as negatives
+fn split_sign<F: PrimeField>(coefficient: F) -> (bool, F) {
+    if coefficient.into_bigint() > F::MODULUS_MINUS_ONE_DIV_TWO {
+        (true, -coefficient)
+    } else {
+        (false, coefficient)
+    }
+}
+
+// writes "f(a, b) = " followed by the terms, each term is (variable indices, coefficient)
+fn write_polynomial<F: PrimeField>(
+    f: &mut fmt::Formatter<'_>,
+    number_of_variables: usize,
+    terms: &[(Vec<usize>, F)],
+) -> fmt::Result {
+    let names: Vec<String> = (0..number_of_variables)
+        .map(|i| variable_name(i, number_of_variables))
+        .collect();
+    write!(f, "f({}) = ", names.join(", "))?;
+    if terms.is_empty() {
+        return write!(f, "0");
+    }
+    for (i, (variables, coefficient)) in terms.iter().enumerate() {
+        let (negative, magnitude) = split_sign(*coefficient);
+        match (i == 0, negative) {
+            (true, true) => write!(f, "-")?,
+            (true, false) => {}
+            (false, true) => write!(f, " - ")?,
+            (false, false) => write!(f, " + ")?,
+        }
+        if variables.is_empty() || !magnitude.is_one() {
+            write!(f, "{}", magnitude.into_bigint())?;
+        }
+        for variable in variables {
+            write!(f, "{}", names[*variable])?;
+        }
+    }
+    Ok(())
+}
+
+struct ParsedPolynomial<F: PrimeField> {
+    number_of_variables: usize,
+    // terms in input order, each with its sorted variable indices
+    terms: Vec<(Vec<usize>, F)>,
+}
+
+struct Parser {
+    chars: Vec<char>,
+    position: usize,
+}
+
+impl Parser {
+    fn skip_whitespace(&mut self) {
+        while self.peek().is_some_and(|c| c.is_whitespace()) {
+            self.position += 1;
+        }
+    }
+
+    fn peek(&self) -> Option<char> {
+        self.chars.get(self.position).copied()
+    }
+
+    fn unexpected(&self) -> ParsePolynomialError {
+        match self.peek() {
+            Some(found) => ParsePolynomialError::UnexpectedCharacter {
+                position: self.position,
+                found,
+            },
+            None => ParsePolynomialError::UnexpectedEnd,
+        }
+    }
+
+    fn expect(&mut self, expected: char) -> Result<(), ParsePolynomialError> {
+        self.skip_whitespace();
+        if self.peek() != Some(expected) {
+            return Err(self.unexpected());
+        }
+        self.position += 1;
+        Ok(())
+    }
+
+    fn digits(&mut self) -> String {
+        let start = self.position;
+        while self.peek().is_some_and(|c| c.is_ascii_digit()) {
+            self.position += 1;
+        }
+        self.chars[start..self.position].iter().collect()
+    }
+
+    // identifier := lowercase letter ['_' digits], so "ab" is the product of a and b
+    fn identifier(&mut self) -> Result<Option<String>, ParsePolynomialError> {
+        let letter = match self.peek() {
+            Some(c) if c.is_ascii_lowercase() => c,
+            _ => return Ok(None),
+        };
+        self.position += 1;
+        let mut name = letter.to_string();
+        if self.peek() == Some('_') {
+            self.position += 1;
+            let digits = self.digits();
+            if digits.is_empty() {
+                return Err(self.unexpected());
+            }
+            name.push('_');
+            name.push_str(&digits);
+        }
+        Ok(Some(name))
+    }
+
+    // header := name '(' [identifier (',' identifier)*] ')' '='
+    fn header(&mut self) -> Result<Vec<String>, ParsePolynomialError> {
+        self.skip_whitespace();
+        while self
+            .peek()
+            .is_some_and(|c| c.is_ascii_alphanumeric() || c == '_')
+        {
+            self.position += 1;
+        }
+        self.expect('(')?;
+        let mut names: Vec<String> = Vec::new();
+        self.skip_whitespace();
+        if self.peek() == Some(')') {
+            self.position += 1;
+        } else {
+            loop {
+                self.skip_whitespace();
+                let name = self.identifier()?.ok_or_else(|| self.unexpected())?;
+                if names.contains(&name) {
+                    return Err(ParsePolynomialError::RepeatedVariable(name));
+                }
+                names.push(name);
+                self.skip_whitespace();
+                match self.peek() {
+                    Some(',') => self.position += 1,
+                    Some(')') => {
+                        self.position += 1;
+                        break;
+                    }
+                    _ => return Err(self.unexpected()),
+                }
+            }
+        }
+        self.expect('=')?;
+        Ok(names)
+    }
+
+    // term := [coefficient] ['*'] identifier (['*'] identifier)*
+    fn term<F: PrimeField>(&mut self) -> Result<(Vec<String>, F), ParsePolynomialError> {
+        self.skip_whitespace();
+        let digits = self.digits();
+        let has_coefficient = !digits.is_empty();
+        let coefficient = if has_coefficient {
+            F::from_str(&digits).map_err(|_| ParsePolynomialError::InvalidCoefficient(digits))?
+        } else {
+            F::one()
+        };
+        let mut names = Vec::new();
+        loop {
+            self.skip_whitespace();
+            let has_star = self.peek() == Some('*');
+            if has_star {
+                if !has_coefficient && names.is_empty() {
+                    return Err(self.unexpected());
+                }
+                self.position += 1;
+                self.skip_whitespace();
+            }
+            match self.identifier()? {
+                Some(name) => names.push(name),
+                None if has_star || (!has_coefficient && names.is_empty()) => {
+                    return Err(self.unexpected())
+                }
+                None => break,
+            }
+        }
+        Ok((names, coefficient))
+    }
+}
+
+fn parse_polynomial<F: PrimeField>(s: &str) -> Result<ParsedPolynomial<F>, ParsePolynomialError> {
+    let mut parser = Parser {
+        chars: s.chars().collect(),
+        position: 0,
+    };
+    let header = if s.contains('=') {
+        Some(parser.header()?)
+    } else {
+        None
+    };
+    parser.skip_whitespace();
+    if parser.peek().is_none() {
+        return Err(ParsePolynomialError::Empty);
+    }
+
+    let mut named_terms = Vec::new();
+    let mut negative = false;
+    if let Some(sign @ ('+' | '-')) = parser.peek() {
+        negative = sign == '-';
+        parser.position += 1;
+    }
+    loop {
+        let (names, coefficient) = parser.term::<F>()?;
+        named_terms.push((names, if negative { -coefficient } else { coefficient }));
+        parser.skip_whitespace();
+        match parser.peek() {
+            None => break,
+            Some(sign @ ('+' | '-')) => {
+                negative = sign == '-';
+                parser.position += 1;
+            }
+            Some(_) => return Err(parser.unexpected()),
+        }
+    }
+
+    if header.is_none() {
+        check_letter_prefix(named_terms.iter().flat_map(|(names, _)| names))?;
+    }
+    let resolve = |name: &String| -> Result<usize, ParsePolynomialError> {
+        let index = match &header {
+            Some(header) => header.iter().position(|declared| declared == name),
+            None => default_variable_index(name),
+        };
+        index.ok_or_else(|| ParsePolynomialError::UnknownVariable(name.clone()))
+    };
+    let mut terms = Vec::with_capacity(named_terms.len());
+    for (names, coefficient) in named_terms {
+        let mut variables = Vec::with_capacity(names.len());
+        for name in names.iter() {
+            let index = resolve(name)?;
+            if variables.contains(&index) {
+                return Err(ParsePolynomialError::RepeatedVariable(name.clone()));
+            }
+            variables.push(index);
+        }
+        variables.sort();
+        terms.push((variables, coefficient));
+    }
+    let number_of_variables = match header {
+        Some(header) => header.len(),
+        None => terms
+            .iter()
+            .flat_map(|(variables, _)| variables.last())
+            .max()
+            .map_or(0, |last| last + 1),
+    };
+    Ok(ParsedPolynomial {
+        number_of_variables,
+        terms,
+    })
+}
+
+/// Prints the polynomial in coefficient form, e.g `f(a, b) = 3ab + 2a + 1`, terms ordered from
+/// `ab...` down to the constant.
+impl<F: PrimeField> fmt::Display for DenseMultilinear<F> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let number_of_variables = self.number_of_variables as usize;
+        let terms: Vec<(Vec<usize>, F)> = self
+            .coefficients
+            .iter()
+            .enumerate()
+            .rev()
+            .filter(|(_, coefficient)| !coefficient.is_zero())
+            .map(|(monomial, coefficient)| {
+                (
+                    monomial_variables(monomial, number_of_variables),
+                    *coefficient,
+                )
+            })
+            .collect();
+        write_polynomial(f, number_of_variables, &terms)
+    }
+}
+
+impl<F: PrimeField> FromStr for DenseMultilinear<F> {
+    type Err = ParsePolynomialError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let parsed = parse_polynomial::<F>(s)?;
+        let number_of_variables = parsed.number_of_variables;
+        if number_of_variables > MAX_PARSED_VARIABLES {
+            return Err(ParsePolynomialError::TooManyVariables(number_of_variables));
+        }
+        let mut coefficients = vec![F::zero(); 1 << number_of_variables];
+        for (variables, coefficient) in parsed.terms {
+            coefficients[variables_monomial(&variables, number_of_variables)] += coefficient;
+        }
+        Ok(DenseMultilinear::new(coefficients))
+    }
+}
+
+/// Prints the coefficient form of the polynomial, see `DenseMultilinear`.
+impl<F: PrimeField> fmt::Display for MultilinearEvalForm<F> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        DenseMultilinear::from_eval_form(self).fmt(f)
+    }
+}
+
+impl<F: PrimeField> FromStr for MultilinearEvalForm<F> {
+    type Err = ParsePolynomialError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Ok(s.parse::<DenseMultilinear<F>>()?.to_eval_form())
+    }
+}
+
+// the variables of a monomial bitmask, first variable = most significant bit
+fn monomial_variables(monomial: usize, number_of_variables: usize) -> Vec<usize> {
+    (0..number_of_variables)
+        .filter(|i| monomial >> (number_of_variables - 1 - i) & 1 == 1)
+        .collect()
+}
+
+// the inverse of monomial_variables
+fn variables_monomial(variables: &[usize], number_of_variables: usize) -> usize {
+    variables
+        .iter()
+        .map(|i| 1 << (number_of_variables - 1 - i))
+        .sum()
+}
+
+/// Prints the terms like `DenseMultilinear`, from the highest monomial down to the constant.
+impl<F: PrimeField> fmt::Display for SparseMultilinear<F> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let number_of_variables = self.number_of_variables as usize;
+        let terms: Vec<(Vec<usize>, F)> = self
+            .terms
+            .iter()
+            .rev()
+            .map(|(monomial, coefficient)| {
+                (
+                    monomial_variables(*monomial, number_of_variables),
+                    *coefficient,
+                )
+            })
+            .collect();
+        write_polynomial(f, number_of_variables, &terms)
+    }
+}
+
+/// Repeated monomials are added together, see `SparseMultilinear::new`.
+impl<F: PrimeField> FromStr for SparseMultilinear<F> {
+    type Err = ParsePolynomialError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let parsed = parse_polynomial::<F>(s)?;
+        let number_of_variables = parsed.number_of_variables;
+        if number_of_variables > MAX_SPARSE_VARIABLES as usize {
+            return Err(ParsePolynomialError::TooManyVariables(number_of_variables));
+        }
+        let terms = parsed
+            .terms
+            .into_iter()
+            .map(|(variables, coefficient)| {
+                (
+                    variables_monomial(&variables, number_of_variables),
+                    coefficient,
+                )
+            })
+            .collect();
+        Ok(SparseMultilinear::new(number_of_variables as u32, terms))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::evaluation_form::convert_to_fq_elements;
+    use ark_bn254::Fq;
+
+    #[test]
+    fn test_display_eval_form() {
+        // f(a, b) = 3ab + 2a + 1 over 00, 01, 10, 11
+        let poly = MultilinearEvalForm::new(convert_to_fq_elements(vec![1, 1, 3, 6]));
+        assert_eq!(poly.to_string(), "f(a, b) = 3ab + 2a + 1");
+
+        let poly = MultilinearEvalForm::new(vec![Fq::from(0), -Fq::from(1)]);
+        assert_eq!(poly.to_string(), "f(a) = -a");
+        let poly = MultilinearEvalForm::new(vec![Fq::from(0); 4]);
+        assert_eq!(poly.to_string(), "f(a, b) = 0");
+    }
+
+    #[test]
+    fn test_dense_multilinear_round_trip() {
+        let dense: DenseMultilinear<Fq> = "f(a, b, c) = 5abc - 2ac + 7".parse().unwrap();
+        assert_eq!(dense.coefficient(&[0, 1, 2]), Fq::from(5));
+        assert_eq!(dense.coefficient(&[0, 2]), -Fq::from(2));
+        assert_eq!(dense.to_string(), "f(a, b, c) = 5abc - 2ac + 7");
+        assert_eq!(dense.to_eval_form().to_string(), dense.to_string());
+    }
+
+    #[test]
+    fn test_parse_eval_form() {
+        let poly: MultilinearEvalForm<Fq> = "f(a, b) = 3ab + 2a + 1".parse().unwrap();
+        assert_eq!(poly.eval_form, convert_to_fq_elements(vec![1, 1, 3, 6]));
+
+        // without a header the variables are inferred
+        let poly: MultilinearEvalForm<Fq> = "2*a*b - c".parse().unwrap();
+        assert_eq!(poly.number_of_variables, 3);
+        let mut expected = convert_to_fq_elements(vec![0, 0, 0, 0, 0, 0, 2, 2]);
+        for (i, value) in expected.iter_mut().enumerate() {
+            if i & 1 == 1 {
+                *value -= Fq::from(1);
+            }
+        }
+        assert_eq!(poly.eval_form, expected);
+
+        // named variables follow the header order
+        let poly: MultilinearEvalForm<Fq> = "g(y, x) = 5x + y".parse().unwrap();
+        assert_eq!(poly.eval_form, convert_to_fq_elements(vec![0, 5, 1, 6]));
+    }
+
+    #[test]
+    fn test_round_trip() {
+        let poly = MultilinearEvalForm::new(convert_to_fq_elements(vec![4, 0, 7, 1, 3, 3, 9, 2]));
+        let parsed: MultilinearEvalForm<Fq> = poly.to_string().parse().unwrap();
+        assert_eq!(parsed.eval_form, poly.eval_form);
+
+        // the header keeps variables the polynomial does not depend on
+        let poly = MultilinearEvalForm::new(convert_to_fq_elements(vec![2, 2, 2, 2]));
+        assert_eq!(poly.to_string(), "f(a, b) = 2");
+        let parsed: MultilinearEvalForm<Fq> = poly.to_string().parse().unwrap();
+        assert_eq!(parsed.eval_form, poly.eval_form);
+
+        let sparse = SparseMultilinear::new(
+            3,
+            vec![
+                (0b101, Fq::from(3)),
+                (0b000, -Fq::from(4)),
+                (0b010, Fq::from(1)),
+            ],
+        );
+        assert_eq!(sparse.to_string(), "f(a, b, c) = 3ac + b - 4");
+        let parsed: SparseMultilinear<Fq> = sparse.to_string().parse().unwrap();
+        assert_eq!(parsed, sparse);
+        // repeated terms are merged
+        let parsed: SparseMultilinear<Fq> = "f(a, b) = 2ab - 4 + 3ab".parse().unwrap();
+        assert_eq!(
+            parsed.terms,
+            vec![(0b00, -Fq::from(4)), (0b11, Fq::from(5))]
+        );
+    }
+
+    #[test]
+    fn test_many_variables() {
+        assert_eq!(variable_name(25, 26), "z");
+        assert_eq!(variable_name(25, 27), "x_26");
+        let sparse: SparseMultilinear<Fq> = "x_1x_27 + 2".parse().unwrap();
+        assert_eq!(sparse.number_of_variables, 27);
+        assert_eq!(sparse.terms[1], ((1 << 26) | 1, Fq::from(1)));
+        let printed = sparse.to_string();
+        assert!(printed.starts_with("f(x_1, x_2, "));
+        assert!(printed.ends_with(") = x_1x_27 + 2"));
+        let parsed: SparseMultilinear<Fq> = printed.parse().unwrap();
+        assert_eq!(parsed, sparse);
+        assert_eq!(
+            "y_2".parse::<SparseMultilinear<Fq>>().err(),
+            Some(ParsePolynomialError::UnknownVariable("y_2".to_string()))
+        );
+    }
+
+    #[test]
+    fn test_parse_errors() {
+        type Result = std::result::Result<SparseMultilinear<Fq>, ParsePolynomialError>;
+        assert_eq!(
+            "".parse::<SparseMultilinear<Fq>>().err(),
+            Some(ParsePolynomialError::Empty)
+        );
+        assert_eq!(
+            ("f(a) = ".parse() as Result).err(),
+            Some(ParsePolynomialError::Empty)
+        );
+        assert_eq!(
+            ("3ab +".parse() as Result).err(),
+            Some(ParsePolynomialError::UnexpectedEnd)
+        );
+        assert_eq!(
+            ("3aB".parse() as Result).err(),
+            Some(ParsePolynomialError::UnexpectedCharacter {
+                position: 2,
+                found: 'B'
+            })
+        );
+        assert_eq!(
+            ("f(a, b) = ac".parse() as Result).err(),
+            Some(ParsePolynomialError::UnknownVariable("c".to_string()))
+        );
+        assert_eq!(
+            ("3aba".parse() as Result).err(),
+            Some(ParsePolynomialError::RepeatedVariable("a".to_string()))
+        );
+        assert_eq!(
+            ("f(a, a) = a".parse() as Result).err(),
+            Some(ParsePolynomialError::RepeatedVariable("a".to_string()))
+        );
+        // without a header the letters must start from a
+        assert_eq!(
+            "x + y".parse::<MultilinearEvalForm<Fq>>().err(),
+            Some(ParsePolynomialError::UnknownVariable("x".to_string()))
+        );
+        assert_eq!(
+            ("ab + d".parse() as Result).err(),
+            Some(ParsePolynomialError::UnknownVariable("d".to_string()))
+        );
+        let poly: MultilinearEvalForm<Fq> = "f(x, y) = x + y".parse().unwrap();
+        assert_eq!(poly.number_of_variables, 2);
+        assert_eq!(
+            "x_40".parse::<MultilinearEvalForm<Fq>>().err(),
+            Some(ParsePolynomialError::TooManyVariables(40))
+        );
+        // just over the cap, for both forms that store 2^n values
+        let over_the_cap = format!("x_{}", MAX_PARSED_VARIABLES + 1);
+        assert_eq!(
+            over_the_cap.parse::<DenseMultilinear<Fq>>().err(),
+            Some(ParsePolynomialError::TooManyVariables(
+                MAX_PARSED_VARIABLES + 1
+            ))
+        );
+        assert_eq!(
+            over_the_cap.parse::<MultilinearEvalForm<Fq>>().err(),
+            Some(ParsePolynomialError::TooManyVariables(
+                MAX_PARSED_VARIABLES + 1
+            ))
+        );
+    }
+}
@@ -0,0 +1,345 @@
+use crate::coefficient_form::sparse_multilinear::{SparseMultilinear, MAX_SPARSE_VARIABLES};
+use crate::evaluation_form::{MultilinearEvalForm, ProdPoly, SumPoly};
+use ark_ff::PrimeField;
+use ark_serialize::{
+    CanonicalDeserialize, CanonicalSerialize, Compress, Read, SerializationError, Valid, Validate,
+    Write,
+};
+
+// ==============================================================//
+//    @note canonical (de)serialization
+// =============================================================//
+
+// every vector is written with a u64 length prefix, field elements use the arkworks encoding
+// - MultilinearEvalForm: the evaluations over the boolean hypercube
+// - ProdPoly: its multilinear factors
+// - SumPoly: its product polys
+// - SparseMultilinear: the number of variables (u32), then the (monomial, coefficient) terms
+// the dense forms recover the number of variables from the length of the data
+
+impl<F: PrimeField> CanonicalSerialize for MultilinearEvalForm<F> {
+    fn serialize_with_mode<W: Write>(
+        &self,
+        writer: W,
+        compress: Compress,
+    ) -> Result<(), SerializationError> {
+        self.eval_form.serialize_with_mode(writer, compress)
+    }
+
+    fn serialized_size(&self, compress: Compress) -> usize {
+        self.eval_form.serialized_size(compress)
+    }
+}
+
+impl<F: PrimeField> Valid for MultilinearEvalForm<F> {
+    fn check(&self) -> Result<(), SerializationError> {
+        let length = self.eval_form.len();
+        if !length.is_power_of_two() || length > u32::MAX as usize {
+            return Err(SerializationError::InvalidData);
+        }
+        if self.number_of_variables != length.trailing_zeros() {
+            return Err(SerializationError::InvalidData);
+        }
+        Ok(())
+    }
+}
+
+impl<F: PrimeField> CanonicalDeserialize for MultilinearEvalForm<F> {
+    fn deserialize_with_mode<R: Read>(
+        reader: R,
+        compress: Compress,
+        validate: Validate,
+    ) -> Result<Self, SerializationError> {
+        let eval_form = Vec::<F>::deserialize_with_mode(reader, compress, validate)?;
+        // a length that is not a power of two has no number of variables, so this is checked
+        // even when validation is skipped
+        if !eval_form.len().is_power_of_two() || eval_form.len() > u32::MAX as usize {
+            return Err(SerializationError::InvalidData);
+        }
+        Ok(MultilinearEvalForm::new(eval_form))
+    }
+}
+
+impl<F: PrimeField> CanonicalSerialize for ProdPoly<F> {
+    fn serialize_with_mode<W: Write>(
+        &self,
+        writer: W,
+        compress: Compress,
+    ) -> Result<(), SerializationError> {
+        self.polynomials.serialize_with_mode(writer, compress)
+    }
+
+    fn serialized_size(&self, compress: Compress) -> usize {
+        self.polynomials.serialized_size(compress)
+    }
+}
+
+// the same invariants `ProdPoly::new` panics on
+impl<F: PrimeField> Valid for ProdPoly<F> {
+    fn check(&self) -> Result<(), SerializationError> {
+        if self.polynomials.is_empty() {
+            return Err(SerializationError::InvalidData);
+        }
+        if self
+            .polynomials
+            .iter()
+            .any(|poly| poly.number_of_variables != self.no_of_vars)
+        {
+            return Err(SerializationError::InvalidData);
+        }
+        self.polynomials.check()
+    }
+}
+
+impl<F: PrimeField> CanonicalDeserialize for ProdPoly<F> {
+    fn deserialize_with_mode<R: Read>(
+        reader: R,
+        compress: Compress,
+        validate: Validate,
+    ) -> Result<Self, SerializationError> {
+        let polynomials =
+            Vec::<MultilinearEvalForm<F>>::deserialize_with_mode(reader, compress, validate)?;
+        let no_of_vars = polynomials
+            .first()
+            .ok_or(SerializationError::InvalidData)?
+            .number_of_variables;
+        let prod_poly = ProdPoly {
+            polynomials,
+            no_of_vars,
+        };
+        if validate == Validate::Yes {
+            prod_poly.check()?;
+        }
+        Ok(prod_poly)
+    }
+}
+
+impl<F: PrimeField> CanonicalSerialize for SumPoly<F> {
+    fn serialize_with_mode<W: Write>(
+        &self,
+        writer: W,
+        compress: Compress,
+    ) -> Result<(), SerializationError> {
+        self.product_polys.serialize_with_mode(writer, compress)
+    }
+
+    fn serialized_size(&self, compress: Compress) -> usize {
+        self.product_polys.serialized_size(compress)
+    }
+}
+
+// every product poly has to be over the same variables for `reduce` to make sense
+impl<F: PrimeField> Valid for SumPoly<F> {
+    fn check(&self) -> Result<(), SerializationError> {
+        let no_of_vars = self
+            .product_polys
+            .first()
+            .ok_or(SerializationError::InvalidData)?
+            .no_of_vars;
+        if self
+            .product_polys
+            .iter()
+            .any(|prod_poly| prod_poly.no_of_vars != no_of_vars)
+        {
+            return Err(SerializationError::InvalidData);
+        }
+        self.product_polys.check()
+    }
+}
+
+impl<F: PrimeField> CanonicalDeserialize for SumPoly<F> {
+    fn deserialize_with_mode<R: Read>(
+        reader: R,
+        compress: Compress,
+        validate: Validate,
+    ) -> Result<Self, SerializationError> {
+        let product_polys = Vec::<ProdPoly<F>>::deserialize_with_mode(reader, compress, validate)?;
+        if product_polys.is_empty() {
+            return Err(SerializationError::InvalidData);
+        }
+        let sum_poly = SumPoly { product_polys };
+        if validate == Validate::Yes {
+            sum_poly.check()?;
+        }
+        Ok(sum_poly)
+    }
+}
+
+// monomials are written as u64 so the encoding does not depend on the platform's usize
+fn sparse_terms<F: PrimeField>(polynomial: &SparseMultilinear<F>) -> Vec<(u64, F)> {
+    polynomial
+        .terms
+        .iter()
+        .map(|(monomial, coefficient)| (*monomial as u64, *coefficient))
+        .collect()
+}
+
+impl<F: PrimeField> CanonicalSerialize for SparseMultilinear<F> {
+    fn serialize_with_mode<W: Write>(
+        &self,
+        mut writer: W,
+        compress: Compress,
+    ) -> Result<(), SerializationError> {
+        self.number_of_variables
+            .serialize_with_mode(&mut writer, compress)?;
+        sparse_terms(self).serialize_with_mode(writer, compress)
+    }
+
+    fn serialized_size(&self, compress: Compress) -> usize {
+        self.number_of_variables.serialized_size(compress)
+            + sparse_terms(self).serialized_size(compress)
+    }
+}
+
+// the terms must be in the canonical form SparseMultilinear::new produces: strictly increasing
+// monomials and no zero coefficients
+impl<F: PrimeField> Valid for SparseMultilinear<F> {
+    fn check(&self) -> Result<(), SerializationError> {
+        if self.number_of_variables > MAX_SPARSE_VARIABLES {
+            return Err(SerializationError::InvalidData);
+        }
+        if self.terms.iter().any(|(monomial, coefficient)| {
+            coefficient.is_zero()
+                || monomial
+                    .checked_shr(self.number_of_variables)
+                    .is_some_and(|high| high != 0)
+        }) {
+            return Err(SerializationError::InvalidData);
+        }
+        if self.terms.windows(2).any(|pair| pair[0].0 >= pair[1].0) {
+            return Err(SerializationError::InvalidData);
+        }
+        Ok(())
+    }
+}
+
+impl<F: PrimeField> CanonicalDeserialize for SparseMultilinear<F> {
+    fn deserialize_with_mode<R: Read>(
+        mut reader: R,
+        compress: Compress,
+        validate: Validate,
+    ) -> Result<Self, SerializationError> {
+        let number_of_variables = u32::deserialize_with_mode(&mut reader, compress, validate)?;
+        let terms = Vec::<(u64, F)>::deserialize_with_mode(reader, compress, validate)?
+            .into_iter()
+            .map(|(monomial, coefficient)| {
+                usize::try_from(monomial)
+                    .map(|monomial| (monomial, coefficient))
+                    .map_err(|_| SerializationError::InvalidData)
+            })
+            .collect::<Result<Vec<_>, _>>()?;
+        let sparse = SparseMultilinear {
+            number_of_variables,
+            terms,
+        };
+        if validate == Validate::Yes {
+            sparse.check()?;
+        }
+        Ok(sparse)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::evaluation_form::convert_to_fq_elements;
+    use ark_bn254::Fq;
+
+    fn round_trip<T: CanonicalSerialize + CanonicalDeserialize>(value: &T) -> T {
+        let mut compressed = Vec::new();
+        value.serialize_compressed(&mut compressed).unwrap();
+        assert_eq!(compressed.len(), value.compressed_size());
+        let mut uncompressed = Vec::new();
+        value.serialize_uncompressed(&mut uncompressed).unwrap();
+        assert_eq!(uncompressed.len(), value.uncompressed_size());
+        T::deserialize_uncompressed(&uncompressed[..]).unwrap();
+        T::deserialize_compressed(&compressed[..]).unwrap()
+    }
+
+    #[test]
+    fn test_eval_form_round_trip() {
+        let poly = MultilinearEvalForm::new(convert_to_fq_elements(vec![0, 0, 0, 3, 0, 0, 2, 5]));
+        let decoded = round_trip(&poly);
+        assert_eq!(decoded.eval_form, poly.eval_form);
+        assert_eq!(decoded.number_of_variables, 3);
+
+        // a partially evaluated polynomial decodes to one that keeps evaluating the same way
+        let mut partial = poly.clone();
+        partial.partial_evaluate(1, Fq::from(4));
+        let mut decoded = round_trip(&partial);
+        assert_eq!(decoded.number_of_variables, 2);
+        assert_eq!(
+            decoded.partial_evaluate(2, Fq::from(7)),
+            partial.partial_evaluate(2, Fq::from(7))
+        );
+    }
+
+    #[test]
+    fn test_sum_poly_round_trip() {
+        let poly_1 = MultilinearEvalForm::new(convert_to_fq_elements(vec![0, 0, 0, 2]));
+        let poly_2 = MultilinearEvalForm::new(convert_to_fq_elements(vec![0, 3, 0, 3]));
+        let prod_poly = ProdPoly::new(vec![poly_1.clone(), poly_2.clone()]);
+        let sum_poly = SumPoly::new(vec![prod_poly.clone(), ProdPoly::new(vec![poly_2])]);
+
+        let decoded = round_trip(&prod_poly);
+        assert_eq!(decoded.no_of_vars, 2);
+        assert_eq!(decoded.reduce(), prod_poly.reduce());
+
+        let decoded = round_trip(&sum_poly);
+        assert_eq!(decoded.product_polys.len(), 2);
+        assert_eq!(decoded.reduce(), sum_poly.reduce());
+    }
+
+    #[test]
+    fn test_sparse_round_trip() {
+        let sparse: SparseMultilinear<Fq> = "f(a, b, c) = 3ab + 2c - 1".parse().unwrap();
+        let decoded = round_trip(&sparse);
+        assert_eq!(decoded, sparse);
+    }
+
+    #[test]
+    fn test_rejects_invalid_data() {
+        // three evaluations is not a boolean hypercube
+        let mut bytes = Vec::new();
+        convert_to_fq_elements(vec![1, 2, 3])
+            .serialize_compressed(&mut bytes)
+            .unwrap();
+        assert!(MultilinearEvalForm::<Fq>::deserialize_compressed_unchecked(&bytes[..]).is_err());
+
+        // factors over a different number of variables
+        let mut bytes = Vec::new();
+        vec![
+            MultilinearEvalForm::new(convert_to_fq_elements(vec![1, 2])),
+            MultilinearEvalForm::new(convert_to_fq_elements(vec![1, 2, 3, 4])),
+        ]
+        .serialize_compressed(&mut bytes)
+        .unwrap();
+        assert!(ProdPoly::<Fq>::deserialize_compressed(&bytes[..]).is_err());
+
+        // no product polys at all
+        let mut bytes = Vec::new();
+        Vec::<ProdPoly<Fq>>::new()
+            .serialize_compressed(&mut bytes)
+            .unwrap();
+        assert!(SumPoly::<Fq>::deserialize_compressed(&bytes[..]).is_err());
+
+        // repeated monomials are not canonical
+        let sparse = SparseMultilinear {
+            number_of_variables: 2,
+            terms: vec![(0b01, Fq::from(1)), (0b01, Fq::from(2))],
+        };
+        let mut bytes = Vec::new();
+        sparse.serialize_compressed(&mut bytes).unwrap();
+        assert!(SparseMultilinear::<Fq>::deserialize_compressed(&bytes[..]).is_err());
+        assert!(SparseMultilinear::<Fq>::deserialize_compressed_unchecked(&bytes[..]).is_ok());
+
+        // a monomial using a third variable
+        let sparse = SparseMultilinear {
+            number_of_variables: 2,
+            terms: vec![(0b100, Fq::from(1))],
+        };
+        let mut bytes = Vec::new();
+        sparse.serialize_compressed(&mut bytes).unwrap();
+        assert!(SparseMultilinear::<Fq>::deserialize_compressed(&bytes[..]).is_err());
+    }
+}
@@ -1,6 +1,7 @@
 use ark_ff::PrimeField;
 use rand;
 use univariate::dense_polynomial::UnivariatePolynomialDense;
+use univariate::reed_solomon::{DecodingError, ReedSolomonCode};
 // this fn takes in a secret, threshold, and shares_no, it returns a vector of tuples representing the shares
 // instead of making the secret a point at 0, we can make it a point at any x value, where x is the password
 // todo: implement the function
@@ -22,7 +23,7 @@ pub fn share_secret<F: PrimeField>(secret: F, threshold: u64, shares_no: u64) ->
 
     let poly = UnivariatePolynomialDense::new(y_values);
 
-    let mut shares: Vec<(F, F)> = Vec::new();
+    let mut share_x_values: Vec<F> = Vec::new();
 
     for _i in 0..shares_no {
         let mut x: F = F::rand(&mut rng);
@@ -30,10 +31,10 @@ pub fn share_secret<F: PrimeField>(secret: F, threshold: u64, shares_no: u64) ->
         while x == F::zero() {
             x = F::rand(&mut rng);
         }
-        let y = poly.evaluate(x as F);
-        shares.push((x as F, y as F));
+        share_x_values.push(x);
     }
-    shares
+    let share_y_values = poly.evaluate_many(&share_x_values);
+    share_x_values.into_iter().zip(share_y_values).collect()
 }
 
 pub fn share_secret_with_password<F: PrimeField>(
@@ -63,21 +64,21 @@ pub fn share_secret_with_password<F: PrimeField>(
     }
 
     let poly = UnivariatePolynomialDense::interpolate(x_values, y_values);
-    if poly.degree != threshold - 1 {
+    if poly.degree() != threshold - 1 {
         panic!("Polynomial interpolation failed");
     }
 
-    let mut shares: Vec<(F, F)> = Vec::new();
+    let mut share_x_values: Vec<F> = Vec::new();
 
     for _i in 0..shares_no {
         let mut x: F = F::rand(&mut rng);
         while x == password {
             x = F::rand(&mut rng);
         }
-        let y = poly.evaluate(x as F);
-        shares.push((x as F, y as F));
+        share_x_values.push(x);
     }
-    shares
+    let share_y_values = poly.evaluate_many(&share_x_values);
+    share_x_values.into_iter().zip(share_y_values).collect()
 }
 
 // recovers the secret from the shares
@@ -89,6 +90,23 @@ pub fn recover_secret<F: PrimeField>(secret_shares: Vec<(F, F)>, password: F) ->
     resulting_poly.evaluate(F::from(password))
 }
 
+// recovers the secret even when some shares are corrupted, the shares form a Reed-Solomon
+// codeword so up to (shares - threshold) / 2 bad shares are corrected
+// returns the secret and the indices of the bad shares, or an error when threshold is 0,
+// there are fewer shares than threshold or two shares have the same x
+pub fn recover_secret_with_errors<F: PrimeField>(
+    secret_shares: Vec<(F, F)>,
+    threshold: u64,
+    password: F,
+) -> Result<(F, Vec<usize>), DecodingError> {
+    let x_values: Vec<F> = secret_shares.iter().map(|(x, _y)| *x).collect();
+    let y_values: Vec<F> = secret_shares.iter().map(|(_x, y)| *y).collect();
+    let code = ReedSolomonCode::try_new(x_values, threshold as usize)?;
+    let (resulting_poly, bad_shares) = code.decode(&y_values)?;
+
+    Ok((resulting_poly.evaluate(password), bad_shares))
+}
+
 #[cfg(test)]
 
 mod tests {
@@ -132,4 +150,46 @@ mod tests {
 
         assert_eq!(Fq::from(secret), Fq::from(recovered_secret));
     }
+
+    #[test]
+    fn test_recover_secret_with_errors() {
+        let (secret, threshold, shares_no) = return_values();
+        let password = Fq::from(7);
+        let mut shares = share_secret_with_password(secret, threshold, shares_no, password);
+        shares[2].1 += Fq::from(1);
+        shares[8].1 = Fq::from(0);
+
+        let (recovered_secret, bad_shares) =
+            recover_secret_with_errors(shares.clone(), threshold, password).unwrap();
+        assert_eq!(recovered_secret, secret);
+        assert_eq!(bad_shares, vec![2, 8]);
+
+        shares[5].1 += Fq::from(1);
+        shares[6].1 += Fq::from(1);
+        assert_eq!(
+            recover_secret_with_errors(shares, threshold, password),
+            Err(DecodingError::TooManyErrors)
+        );
+    }
+
+    #[test]
+    fn test_recover_secret_with_errors_bad_shares() {
+        let (secret, threshold, shares_no) = return_values();
+        let password = Fq::from(7);
+        let shares = share_secret_with_password(secret, threshold, shares_no, password);
+        assert_eq!(
+            recover_secret_with_errors(shares.clone(), 0, password),
+            Err(DecodingError::InvalidMessageLength)
+        );
+        assert_eq!(
+            recover_secret_with_errors(shares[..3].to_vec(), threshold, password),
+            Err(DecodingError::InvalidMessageLength)
+        );
+        let mut repeated = shares.clone();
+        repeated[4] = repeated[1];
+        assert_eq!(
+            recover_secret_with_errors(repeated, threshold, password),
+            Err(DecodingError::RepeatedEvaluationPoint)
+        );
+    }
 }
@@ -35,7 +35,7 @@ mod tests {
         let poly = UnivariatePolynomialDense::interpolate(x_values, y_values);
 
         // test for degree
-        assert_eq!(poly.degree, 7);
+        assert_eq!(poly.degree(), 7);
 
         // test boundary condition
         assert_eq!(poly.evaluate(Fq::from(0)), Fq::from(1));
@@ -48,6 +48,18 @@ mod tests {
             poly.evaluate(x - Fq::from(1)) + poly.evaluate(x - Fq::from(2))
         );
 
+        // the transition constraint f(x) - f(x - 1) - f(x - 2) vanishes on every step 2..=7
+        let constraint = poly
+            .polynomial_subtraction(&poly.shift(-Fq::from(1)))
+            .polynomial_subtraction(&poly.shift(-Fq::from(2)));
+        for step in 2..8 {
+            assert_eq!(constraint.evaluate(Fq::from(step)), Fq::from(0));
+        }
+        assert_eq!(
+            poly.shift(-Fq::from(1)),
+            poly.compose(&UnivariatePolynomialDense::new(vec![-Fq::from(1), Fq::from(1)]))
+        );
+
         // f(7) = 21
 
         assert_eq!(poly.evaluate(Fq::from(7)), Fq::from(21));
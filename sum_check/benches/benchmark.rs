@@ -4,11 +4,11 @@ use multilinear::evaluation_form::{gen_based_on_two, MultilinearEvalForm};
 use sum_check::sum_check::{get_sum_over_hypercube, prove, verify};
 
 fn benchmark(c: &mut Criterion) -> () {
-    let mut poly = MultilinearEvalForm::new(vec![Fq::from(0), Fq::from(3), Fq::from(2), Fq::from(5)]);
+    let poly = MultilinearEvalForm::new(vec![Fq::from(0), Fq::from(3), Fq::from(2), Fq::from(5)]);
     let sum1 = get_sum_over_hypercube(&poly.eval_form);
-    let proof = prove(&mut poly.clone(), sum1);
+    let proof = prove(&poly, sum1);
 
-    let mut poly_of_3vars = MultilinearEvalForm::new(vec![
+    let poly_of_3vars = MultilinearEvalForm::new(vec![
         Fq::from(0),
         Fq::from(0),
         Fq::from(0),
@@ -19,44 +19,44 @@ fn benchmark(c: &mut Criterion) -> () {
         Fq::from(5),
     ]);
     let sum_of_3 = get_sum_over_hypercube(&poly_of_3vars.eval_form);
-    let proof_of_3 = prove(&mut poly_of_3vars.clone(), sum_of_3);
+    let proof_of_3 = prove(&poly_of_3vars, sum_of_3);
 
-    let mut poly_of_10vars = MultilinearEvalForm::<Fq>::new(gen_based_on_two(10));
+    let poly_of_10vars = MultilinearEvalForm::<Fq>::new(gen_based_on_two(10));
     let sum_of_10 = get_sum_over_hypercube(&poly_of_10vars.eval_form);
-    let proof_of_10 = prove(&mut poly_of_10vars.clone(), sum_of_10);
+    let proof_of_10 = prove(&poly_of_10vars, sum_of_10);
 
-    let mut poly_of_20vars = MultilinearEvalForm::<Fq>::new(gen_based_on_two(20));
+    let poly_of_20vars = MultilinearEvalForm::<Fq>::new(gen_based_on_two(20));
     let sum_of_20 = get_sum_over_hypercube(&poly_of_10vars.eval_form);
-    let proof_of_20 = prove(&mut poly_of_20vars.clone(), sum_of_20);
+    let proof_of_20 = prove(&poly_of_20vars, sum_of_20);
 
     let mut group = c.benchmark_group("sum_check");
 
     // benching prove
     group.bench_function("prove poly of 2vars", |b| {
-        b.iter(|| black_box(prove(&mut poly.clone(), sum1)));
+        b.iter(|| black_box(prove(&poly, sum1)));
     });
     group.bench_function("prove poly of 3vars", |b| {
-        b.iter(|| black_box(prove(&mut poly_of_3vars.clone(), sum_of_3)));
+        b.iter(|| black_box(prove(&poly_of_3vars, sum_of_3)));
     });
     group.bench_function("prove poly of 10vars", |b| {
-        b.iter(|| black_box(prove(&mut poly_of_10vars.clone(), sum_of_10)));
+        b.iter(|| black_box(prove(&poly_of_10vars, sum_of_10)));
     });
     group.bench_function("prove poly of 20vars", |b| {
-        b.iter(|| black_box(prove(&mut poly_of_20vars.clone(), sum_of_20)));
+        b.iter(|| black_box(prove(&poly_of_20vars, sum_of_20)));
     });
 
     // benching verify
     group.bench_function("verify poly of 2vars", |b| {
-        b.iter(|| black_box(verify(proof.clone(), &mut poly)));
+        b.iter(|| black_box(verify(proof.clone(), &poly)));
     });
     group.bench_function("verify poly of 3vars", |b| {
-        b.iter(|| black_box(verify(proof_of_3.clone(), &mut poly_of_3vars)));
+        b.iter(|| black_box(verify(proof_of_3.clone(), &poly_of_3vars)));
     });
     group.bench_function("verify poly of 10vars", |b| {
-        b.iter(|| black_box(verify(proof_of_10.clone(), &mut poly_of_10vars)));
+        b.iter(|| black_box(verify(proof_of_10.clone(), &poly_of_10vars)));
     });
     group.bench_function("verify poly of 20vars", |b| {
-        b.iter(|| black_box(verify(proof_of_20.clone(), &mut poly_of_20vars)));
+        b.iter(|| black_box(verify(proof_of_20.clone(), &poly_of_20vars)));
     });
 }
 
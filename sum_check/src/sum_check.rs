@@ -4,6 +4,7 @@ use fiat_shamir::fiat_shamir::Transcript;
 use multilinear::evaluation_form::{
     convert_to_fq_elements, interpolate_and_evaluate, MultilinearEvalForm, ProdPoly, SumPoly,
 };
+use multilinear::hypercube::sub_cube;
 use sha3::{Digest, Keccak256};
 use univariate::dense_polynomial::UnivariatePolynomialDense;
 
@@ -28,9 +29,6 @@ pub fn get_sum_over_hypercube<F: PrimeField>(polynomial: &Vec<F>) -> F {
 // where the starting indexes of the elements in the first is 0
 // and that of the second is 1
 fn split_by_var<F: PrimeField>(arr: &[F], var: usize) -> (Vec<F>, Vec<F>) {
-    let mut left = Vec::new();
-    let mut right = Vec::new();
-
     let max_bits = arr.len().ilog2() as usize;
 
     if var > max_bits {
@@ -44,13 +42,13 @@ fn split_by_var<F: PrimeField>(arr: &[F], var: usize) -> (Vec<F>, Vec<F>) {
         return (arr.to_vec(), Vec::new()); // Edge case: single-element array
     }
 
-    for (i, &val) in arr.iter().enumerate() {
-        if (i >> (max_bits - var)) & 1 == 0 {
-            left.push(val);
-        } else {
-            right.push(val);
-        }
-    }
+    let number_of_variables = max_bits as u32;
+    let left = sub_cube(number_of_variables, var as u32, false)
+        .map(|i| arr[i])
+        .collect();
+    let right = sub_cube(number_of_variables, var as u32, true)
+        .map(|i| arr[i])
+        .collect();
 
     (left, right)
 }
@@ -65,7 +63,7 @@ pub fn evaluate_at_two_vars<F: PrimeField>(poly_vec: &Vec<F>, var: usize) -> Vec
 
 // @note proves that the claim_sum was derived from the polynomial
 // returns a proof
-pub fn prove<F: PrimeField>(polynomial: &mut MultilinearEvalForm<F>, claim_sum: F) -> Proof<F> {
+pub fn prove<F: PrimeField>(polynomial: &MultilinearEvalForm<F>, claim_sum: F) -> Proof<F> {
     let mut transcript: Transcript<F, Keccak256> = Transcript::init(Keccak256::new());
     transcript.append(&MultilinearEvalForm::to_bytes(&polynomial.eval_form));
 
@@ -74,7 +72,7 @@ pub fn prove<F: PrimeField>(polynomial: &mut MultilinearEvalForm<F>, claim_sum:
 
 // @note performs partial prove, does not add initial poly to transcript
 pub fn partial_prove<F: PrimeField>(
-    polynomial: &mut MultilinearEvalForm<F>,
+    polynomial: &MultilinearEvalForm<F>,
     claim_sum: F,
     transcript: &mut Transcript<F, Keccak256>,
 ) -> Proof<F> {
@@ -85,7 +83,9 @@ pub fn partial_prove<F: PrimeField>(
         polynomials: Vec::new(),
     };
     proof.sum = claim_sum;
-    for i in 1..=polynomial.number_of_variables {
+    // the prover folds its own copy, one variable per round
+    let mut polynomial = polynomial.clone();
+    for _ in 0..polynomial.number_of_variables {
         let univariate_poly = evaluate_at_two_vars(&polynomial.eval_form, 1 as usize);
         // evaluate_at_n_vars(&polynomial.eval_form, 1 as usize);
         transcript.append(&MultilinearEvalForm::to_bytes(&univariate_poly));
@@ -93,13 +93,13 @@ pub fn partial_prove<F: PrimeField>(
         let challenge = transcript.hash();
 
         proof.polynomials.push(univariate_poly);
-        polynomial.partial_evaluate(i, challenge);
+        polynomial.fold(challenge);
     }
     proof
 }
 
 //  @note verifies that the claim_sum was gotten from the polynomial based on the proof provided
-pub fn verify<F: PrimeField>(proof: Proof<F>, polynomial: &mut MultilinearEvalForm<F>) -> bool {
+pub fn verify<F: PrimeField>(proof: Proof<F>, polynomial: &MultilinearEvalForm<F>) -> bool {
     let mut transcript: Transcript<F, Keccak256> = Transcript::init(Keccak256::new());
     transcript.append(&MultilinearEvalForm::to_bytes(&polynomial.eval_form));
 
@@ -155,7 +155,7 @@ pub fn partial_verify<F: PrimeField>(
 // =============================================================//
 
 // prove sum over the Boolean HC of a prod poly
-pub fn prove_prod_poly<F: PrimeField>(claim_sum: F, prod_poly: &mut ProdPoly<F>) -> Proof<F> {
+pub fn prove_prod_poly<F: PrimeField>(claim_sum: F, prod_poly: &ProdPoly<F>) -> Proof<F> {
     let mut transcript: Transcript<F, Keccak256> = Transcript::init(Keccak256::new());
     // add polynomial to transcript
     transcript.append(&prod_poly.to_bytes());
@@ -164,7 +164,7 @@ pub fn prove_prod_poly<F: PrimeField>(claim_sum: F, prod_poly: &mut ProdPoly<F>)
 
 //  @note partial prove for prod_poly
 pub fn partial_prove_prod_poly<F: PrimeField>(
-    prod_poly: &mut ProdPoly<F>,
+    prod_poly: &ProdPoly<F>,
     claim_sum: F,
     transcript: &mut Transcript<F, Keccak256>,
 ) -> Proof<F> {
@@ -177,18 +177,18 @@ pub fn partial_prove_prod_poly<F: PrimeField>(
 
     proof.sum = claim_sum;
 
-    for i in 0..prod_poly.no_of_vars {
+    let mut prod_poly = prod_poly.clone();
+    for _ in 0..prod_poly.no_of_vars {
         let mut univariate_poly = vec![F::zero(); 3];
         // replace this fixed value with variable - degree
         // how do i get the degree? (I currently have number_of_variables)
         for j in 0..=2 {
-            let partial_evaluation: Vec<F> =
-                prod_poly.clone().partial_evaluate(i + 1, F::from(j as u32));
+            let partial_evaluation: Vec<F> = prod_poly.fix_variables(&[F::from(j as u32)]).reduce();
             univariate_poly[j] = partial_evaluation.iter().sum();
         }
         transcript.append(&MultilinearEvalForm::to_bytes(&univariate_poly));
         let challenge = transcript.hash();
-        prod_poly.partial_evaluate(i + 1, challenge);
+        prod_poly.fold(challenge);
 
         proof.polynomials.push(univariate_poly);
     }
@@ -196,7 +196,7 @@ pub fn partial_prove_prod_poly<F: PrimeField>(
 }
 
 //  @note verify the proofs of the prove_prod_poly
-pub fn verify_prod_poly<F: PrimeField>(proof: Proof<F>, prod_poly: &mut ProdPoly<F>) -> bool {
+pub fn verify_prod_poly<F: PrimeField>(proof: Proof<F>, prod_poly: &ProdPoly<F>) -> bool {
     let mut transcript: Transcript<F, Keccak256> = Transcript::init(Keccak256::new());
     transcript.append(&prod_poly.to_bytes());
     let (is_partially_verified, claimed_sum, random_challenges) =
@@ -264,7 +264,7 @@ pub fn interpolate_to_univariate<F: PrimeField>(poly: &Vec<F>) -> UnivariatePoly
 //    @note sumcheck over the sum of product of multilinear polys
 // =============================================================//
 
-pub fn prove_sum_poly<F: PrimeField>(claim_sum: F, sum_poly: &mut SumPoly<F>) -> Proof<F> {
+pub fn prove_sum_poly<F: PrimeField>(claim_sum: F, sum_poly: &SumPoly<F>) -> Proof<F> {
     let mut transcript: Transcript<F, Keccak256> = Transcript::init(Keccak256::new());
     transcript.append(&sum_poly.to_bytes());
 
@@ -272,7 +272,7 @@ pub fn prove_sum_poly<F: PrimeField>(claim_sum: F, sum_poly: &mut SumPoly<F>) ->
 }
 
 pub fn partial_prove_sum_poly<F: PrimeField>(
-    sum_poly: &mut SumPoly<F>,
+    sum_poly: &SumPoly<F>,
     claim_sum: F,
     transcript: &mut Transcript<F, Keccak256>,
 ) -> Proof<F> {
@@ -284,40 +284,29 @@ pub fn partial_prove_sum_poly<F: PrimeField>(
     };
     proof.sum = claim_sum;
 
-    for i in 0..sum_poly.get_no_of_vars() {
+    let mut sum_poly = sum_poly.clone();
+    for _ in 0..sum_poly.get_no_of_vars() {
         let mut univariate_poly = vec![F::zero(); 3];
         for prod_poly in &sum_poly.product_polys {
-            let sum1: F = prod_poly
-                .clone()
-                .partial_evaluate(i + 1, F::zero())
-                .iter()
-                .sum();
+            let sum1: F = prod_poly.fix_variables(&[F::zero()]).reduce().iter().sum();
             univariate_poly[0] += sum1;
 
-            let sum2: F = prod_poly
-                .clone()
-                .partial_evaluate(i + 1, F::one())
-                .iter()
-                .sum();
+            let sum2: F = prod_poly.fix_variables(&[F::one()]).reduce().iter().sum();
             univariate_poly[1] += sum2;
 
-            let sum3: F = prod_poly
-                .clone()
-                .partial_evaluate(i + 1, F::from(2))
-                .iter()
-                .sum();
+            let sum3: F = prod_poly.fix_variables(&[F::from(2)]).reduce().iter().sum();
             univariate_poly[2] += sum3;
         }
         transcript.append(&MultilinearEvalForm::to_bytes(&univariate_poly));
         let challenge = transcript.hash();
-        sum_poly.partial_evaluate(i + 1, challenge);
+        sum_poly.fold(challenge);
         proof.polynomials.push(univariate_poly);
     }
     proof
 }
 
 //  @note verify the proofs of the prove_prod_poly
-pub fn verify_sum_poly<F: PrimeField>(proof: Proof<F>, sum_poly: &mut SumPoly<F>) -> bool {
+pub fn verify_sum_poly<F: PrimeField>(proof: Proof<F>, sum_poly: &SumPoly<F>) -> bool {
     let mut transcript: Transcript<F, Keccak256> = Transcript::init(Keccak256::new());
     transcript.append(&sum_poly.to_bytes());
     let (is_partially_verified, claimed_sum, random_challenges) =
@@ -339,6 +328,8 @@ pub fn verify_sum_poly<F: PrimeField>(proof: Proof<F>, sum_poly: &mut SumPoly<F>
 mod tests {
     use super::*;
     use ark_bn254::Fq;
+    use multilinear::coefficient_form::sparse_multilinear::SparseMultilinear;
+    use multilinear::evaluation_form::eq_polynomial::EqPolynomial;
 
     fn get_test_poly() -> MultilinearEvalForm<Fq> {
         MultilinearEvalForm::new(vec![
@@ -452,9 +443,9 @@ mod tests {
 
     #[test]
     fn test_prove() {
-        let mut poly1 = get_test_poly();
+        let poly1 = get_test_poly();
         let sum = get_sum_over_hypercube(&poly1.eval_form);
-        let proof = prove(&mut poly1, sum);
+        let proof = prove(&poly1, sum);
         assert_eq!(proof.sum, Fq::from(10));
     }
     #[test]
@@ -470,63 +461,105 @@ mod tests {
             Fq::from(5),
         ]);
         let challenges = [Fq::from(1),Fq::from(2),Fq::from(3)];
-        for i in 1..=polynomial.number_of_variables {
-            let fl = polynomial.clone().partial_evaluate(i, Fq::from(0));
-            let sl = polynomial.clone().partial_evaluate(i, Fq::from(1));
+        // every round fixes the first variable left
+        for challenge in challenges {
+            let fl = polynomial.clone().partial_evaluate(1, Fq::from(0));
+            let sl = polynomial.clone().partial_evaluate(1, Fq::from(1));
 
-           let eval = polynomial.partial_evaluate(i, challenges[(i - 1) as usize]);
+           let eval = polynomial.partial_evaluate(1, challenge);
         }
+        assert_eq!(polynomial.number_of_variables, 0);
     }
     #[test]
     fn test_prove2() {
-        let mut poly2 = get_test_poly2();
+        let poly2 = get_test_poly2();
         let sum = get_sum_over_hypercube(&poly2.eval_form);
-        let proof = prove(&mut poly2, sum);
+        let proof = prove(&poly2, sum);
         assert_eq!(proof.sum, Fq::from(12));
     }
 
     #[test]
     fn test_prove_and_verify_valid_proof() {
-        let mut poly1 = get_test_poly();
+        let poly1 = get_test_poly();
         let sum = get_sum_over_hypercube(&poly1.eval_form);
-        let proof = prove(&mut poly1.clone(), sum);
-        let is_valid = verify(proof, &mut poly1);
+        let proof = prove(&poly1, sum);
+        let is_valid = verify(proof, &poly1);
         assert_eq!(is_valid, true);
     }
 
     #[test]
     fn test_prove_and_verify_valid_proof2() {
-        let mut poly2 = get_test_poly2();
+        let poly2 = get_test_poly2();
         let sum = get_sum_over_hypercube(&poly2.eval_form);
-        let proof = prove(&mut poly2.clone(), sum);
-        let is_valid = verify(proof, &mut poly2);
+        let proof = prove(&poly2, sum);
+        let is_valid = verify(proof, &poly2);
         assert_eq!(is_valid, true);
     }
 
     #[test]
     fn test_prove_and_verify_valid_proof_of_2vars() {
-        let mut poly3 = get_test_poly3();
+        let poly3 = get_test_poly3();
         let sum = get_sum_over_hypercube(&poly3.eval_form);
-        let proof = prove(&mut poly3.clone(), sum);
-        let is_valid = verify(proof, &mut poly3);
+        let proof = prove(&poly3, sum);
+        let is_valid = verify(proof, &poly3);
         assert_eq!(is_valid, true);
     }
 
     #[test]
     fn test_prove_and_verify_invalid_proof() {
-        let mut poly1 = get_test_poly();
+        let poly1 = get_test_poly();
         let sum = Fq::from(100000); // guessed sum
-        let invalid_proof = prove(&mut poly1.clone(), sum); // invalid proof
-        let is_valid = verify(invalid_proof, &mut poly1);
+        let invalid_proof = prove(&poly1, sum); // invalid proof
+        let is_valid = verify(invalid_proof, &poly1);
         assert_eq!(is_valid, false);
     }
 
+    #[test]
+    fn test_verify_twice_with_same_polynomial() {
+        let poly1 = get_test_poly();
+        let sum = get_sum_over_hypercube(&poly1.eval_form);
+        assert!(verify(prove(&poly1, sum), &poly1));
+        assert!(verify(prove(&poly1, sum), &poly1));
+        assert_eq!(poly1.eval_form, get_test_poly().eval_form);
+
+        let sum_poly = get_sum_of_prod_poly2();
+        let claim_sum = sum_poly.reduce().iter().sum();
+        let proof = prove_sum_poly(claim_sum, &sum_poly);
+        assert!(verify_sum_poly(proof.clone(), &sum_poly));
+        assert!(verify_sum_poly(proof, &sum_poly));
+    }
+
+    #[test]
+    fn test_prove_and_verify_sparse_poly() {
+        // 3ac + b - 4 over (a, b, c), proved from its evaluations over the hypercube
+        let sparse: SparseMultilinear<Fq> = "f(a, b, c) = 3ac + b - 4".parse().unwrap();
+        let poly = sparse.to_eval_form();
+        let sum = get_sum_over_hypercube(&poly.eval_form);
+        // 3 * 2 + 4 - 4 * 8
+        assert_eq!(sum, -Fq::from(22));
+        assert!(verify(prove(&poly, sum), &poly));
+    }
+
+    #[test]
+    fn test_prove_and_verify_prod_poly_with_eq() {
+        // sum_x eq(x, r) * f(x) = f(r), the claim reduction used by zerocheck and GKR
+        let r = convert_to_fq_elements(vec![3, 5, 7]);
+        let poly = get_test_poly();
+        let prod_poly = ProdPoly::new(vec![
+            EqPolynomial::new(r.clone()).to_eval_form(),
+            poly.clone(),
+        ]);
+        let claim_sum = poly.evaluate(&r);
+        let proof = prove_prod_poly(claim_sum, &prod_poly);
+        assert!(verify_prod_poly(proof, &prod_poly));
+    }
+
     #[test]
     fn test_prove_and_verify_prod_poly() {
         let prod_poly = get_prod_poly();
         let claim_sum = prod_poly.reduce().iter().sum();
-        let proof = prove_prod_poly(claim_sum, &mut prod_poly.clone());
-        let is_valid = verify_prod_poly(proof, &mut prod_poly.clone());
+        let proof = prove_prod_poly(claim_sum, &prod_poly);
+        let is_valid = verify_prod_poly(proof, &prod_poly);
 
         assert_eq!(is_valid, true);
     }
@@ -535,8 +568,8 @@ mod tests {
     fn test_prove_and_verify_prod_poly2() {
         let prod_poly = get_prod_poly2();
         let claim_sum = prod_poly.reduce().iter().sum();
-        let proof = prove_prod_poly(claim_sum, &mut prod_poly.clone());
-        let is_valid = verify_prod_poly(proof, &mut prod_poly.clone());
+        let proof = prove_prod_poly(claim_sum, &prod_poly);
+        let is_valid = verify_prod_poly(proof, &prod_poly);
         assert_eq!(is_valid, true);
     }
 
@@ -544,8 +577,8 @@ mod tests {
     fn test_prove_and_verify_prod_poly2_invalid() {
         let prod_poly = get_prod_poly2();
         let invalid_claim_sum = Fq::from(1);
-        let invalid_proof = prove_prod_poly(invalid_claim_sum, &mut prod_poly.clone());
-        let is_valid = verify_prod_poly(invalid_proof, &mut prod_poly.clone());
+        let invalid_proof = prove_prod_poly(invalid_claim_sum, &prod_poly);
+        let is_valid = verify_prod_poly(invalid_proof, &prod_poly);
         assert_eq!(is_valid, false);
     }
 
@@ -553,8 +586,8 @@ mod tests {
     fn test_prove_and_verify_sum_poly() {
         let sum_poly = get_sum_of_prod_poly();
         let claim_sum = sum_poly.reduce().iter().sum();
-        let proof = prove_sum_poly(claim_sum, &mut sum_poly.clone());
-        let is_valid = verify_sum_poly(proof, &mut sum_poly.clone());
+        let proof = prove_sum_poly(claim_sum, &sum_poly);
+        let is_valid = verify_sum_poly(proof, &sum_poly);
         assert_eq!(is_valid, true);
     }
 
@@ -562,8 +595,8 @@ mod tests {
     fn test_prove_and_verify_sum_poly2() {
         let sum_poly = get_sum_of_prod_poly2();
         let claim_sum = sum_poly.reduce().iter().sum();
-        let proof = prove_sum_poly(claim_sum, &mut sum_poly.clone());
-        let is_valid = verify_sum_poly(proof, &mut sum_poly.clone());
+        let proof = prove_sum_poly(claim_sum, &sum_poly);
+        let is_valid = verify_sum_poly(proof, &sum_poly);
         assert_eq!(is_valid, true);
     }
 
@@ -571,8 +604,8 @@ mod tests {
     fn test_prove_and_verify_sum_poly2_invalid() {
         let sum_poly = get_sum_of_prod_poly2();
         let invalid_claim_sum = Fq::from(10000000);
-        let invalid_proof = prove_sum_poly(invalid_claim_sum, &mut sum_poly.clone());
-        let is_valid = verify_sum_poly(invalid_proof, &mut sum_poly.clone());
+        let invalid_proof = prove_sum_poly(invalid_claim_sum, &sum_poly);
+        let is_valid = verify_sum_poly(invalid_proof, &sum_poly);
         assert_eq!(is_valid, false);
     }
 }
@@ -1,10 +1,11 @@
-use ark_bn254::Fq;
+use ark_bn254::{Fq, Fr};
 use ark_ff::PrimeField;
 use criterion::{black_box, criterion_group, criterion_main, Criterion};
 use rand::thread_rng;
 use univariate::dense_polynomial::UnivariatePolynomialDense;
+use univariate::domain::Radix2Domain;
 
-fn benchmark_function(c: &mut Criterion) -> () {
+fn benchmark_function(c: &mut Criterion) {
     let mut group = c.benchmark_group("sum_functions");
     let poly = UnivariatePolynomialDense::new(vec![Fq::from(1), Fq::from(2), Fq::from(3)]);
     let poly2 = UnivariatePolynomialDense::new(vec![Fq::from(5), Fq::from(2)]);
@@ -18,6 +19,22 @@ fn benchmark_function(c: &mut Criterion) -> () {
     group.bench_function("polynomial_multiplication_for_20vars", |b| {
         b.iter(|| black_box(&poly3).polynomial_multiplication(black_box(&poly4)))
     });
+    let poly5 = UnivariatePolynomialDense::<Fr>::new(gen_random_vars(1024));
+    let poly6 = UnivariatePolynomialDense::<Fr>::new(gen_random_vars(1024));
+    group.bench_function("schoolbook_multiplication_for_1024_coeffs", |b| {
+        b.iter(|| black_box(&poly5).schoolbook_multiplication(black_box(&poly6)))
+    });
+    group.bench_function("fft_multiplication_for_1024_coeffs", |b| {
+        b.iter(|| black_box(&poly5).fft_multiplication(black_box(&poly6)))
+    });
+    let poly7 = UnivariatePolynomialDense::<Fq>::new(gen_random_vars(1024));
+    let poly8 = UnivariatePolynomialDense::<Fq>::new(gen_random_vars(1024));
+    group.bench_function("schoolbook_multiplication_for_1024_fq_coeffs", |b| {
+        b.iter(|| black_box(&poly7).schoolbook_multiplication(black_box(&poly8)))
+    });
+    group.bench_function("karatsuba_multiplication_for_1024_fq_coeffs", |b| {
+        b.iter(|| black_box(&poly7).karatsuba_multiplication(black_box(&poly8)))
+    });
     group.bench_function("evaluate", |b| {
         b.iter(|| black_box(poly.evaluate(Fq::from(2))))
     });
@@ -38,6 +55,57 @@ fn benchmark_function(c: &mut Criterion) -> () {
             ))
         });
     });
+    let points = gen_random_vars::<Fr>(1024);
+    let values = poly5.evaluate_many(&points);
+    group.bench_function("evaluate_naive_for_1024_points", |b| {
+        b.iter(|| {
+            black_box(&points)
+                .iter()
+                .map(|x| poly5.evaluate(*x))
+                .collect::<Vec<Fr>>()
+        })
+    });
+    group.bench_function("evaluate_many_for_1024_points", |b| {
+        b.iter(|| black_box(&poly5).evaluate_many(black_box(&points)))
+    });
+    group.bench_function("interpolate_fast_for_1024_points", |b| {
+        b.iter(|| {
+            black_box(UnivariatePolynomialDense::interpolate_fast(
+                black_box(&points),
+                black_box(&values),
+            ))
+        })
+    });
+
+    // the paths split by the `parallel` feature, compare against `cargo bench --features parallel`
+    let domain = Radix2Domain::<Fr>::new(1 << 16).unwrap();
+    let coefficients_of_2_16 = gen_random_vars::<Fr>(1 << 16);
+    group.bench_function("fft_for_2^16_coeffs", |b| {
+        b.iter(|| black_box(domain.fft(black_box(&coefficients_of_2_16))))
+    });
+    let poly9 = UnivariatePolynomialDense::<Fr>::new(gen_random_vars(1 << 14));
+    let poly10 = UnivariatePolynomialDense::<Fr>::new(gen_random_vars(1 << 14));
+    group.bench_function("fft_multiplication_for_2^14_coeffs", |b| {
+        b.iter(|| black_box(&poly9).fft_multiplication(black_box(&poly10)))
+    });
+    let poly11 = UnivariatePolynomialDense::<Fr>::new(gen_random_vars(4096));
+    let poly12 = UnivariatePolynomialDense::<Fr>::new(gen_random_vars(4096));
+    group.bench_function("schoolbook_multiplication_for_4096_coeffs", |b| {
+        b.iter(|| black_box(&poly11).schoolbook_multiplication(black_box(&poly12)))
+    });
+    let points_4096 = gen_random_vars::<Fr>(4096);
+    let values_4096 = poly11.evaluate_many(&points_4096);
+    group.bench_function("evaluate_many_for_4096_points", |b| {
+        b.iter(|| black_box(&poly11).evaluate_many(black_box(&points_4096)))
+    });
+    group.bench_function("interpolate_fast_for_4096_points", |b| {
+        b.iter(|| {
+            black_box(UnivariatePolynomialDense::interpolate_fast(
+                black_box(&points_4096),
+                black_box(&values_4096),
+            ))
+        })
+    });
     group.finish();
 }
 
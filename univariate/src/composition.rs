@@ -0,0 +1,155 @@
+use crate::dense_polynomial::UnivariatePolynomialDense;
+use ark_ff::{PrimeField, Zero};
+
+impl<F: PrimeField> UnivariatePolynomialDense<F> {
+    /// Composition f(g(x)) evaluated with Horner's rule over polynomials.
+    pub fn compose(&self, inner: &UnivariatePolynomialDense<F>) -> UnivariatePolynomialDense<F> {
+        self.coefficients.iter().rev().fold(
+            UnivariatePolynomialDense::zero(),
+            |acc, coefficient| {
+                (&acc * inner)
+                    .polynomial_addition(&UnivariatePolynomialDense::new(vec![*coefficient]))
+            },
+        )
+    }
+
+    // f(c * x): the i-th coefficient is multiplied by c^i, used for AIR terms like f(g * x)
+    pub fn scale(&self, c: F) -> UnivariatePolynomialDense<F> {
+        let mut power = F::one();
+        UnivariatePolynomialDense::new(
+            self.coefficients
+                .iter()
+                .map(|coefficient| {
+                    let scaled = *coefficient * power;
+                    power *= c;
+                    scaled
+                })
+                .collect(),
+        )
+    }
+
+    /// Taylor shift f(x + a) in O(n^2) field operations.
+    ///
+    /// The i-th coefficient of the result is the i-th Hasse derivative of f at a.
+    pub fn shift(&self, a: F) -> UnivariatePolynomialDense<F> {
+        let mut coefficients = self.coefficients.clone();
+        let n = coefficients.len();
+        // repeated synthetic division by (x - a)
+        for i in 0..n {
+            for j in (i..n - 1).rev() {
+                let carry = a * coefficients[j + 1];
+                coefficients[j] += carry;
+            }
+        }
+        UnivariatePolynomialDense::new(coefficients)
+    }
+
+    // formal derivative: d/dx sum a_i x^i = sum i * a_i x^(i - 1)
+    pub fn derivative(&self) -> UnivariatePolynomialDense<F> {
+        UnivariatePolynomialDense::new(
+            self.coefficients
+                .iter()
+                .enumerate()
+                .skip(1)
+                .map(|(i, coefficient)| F::from(i as u64) * coefficient)
+                .collect(),
+        )
+    }
+
+    /// The k-th Hasse derivative: sum_i binomial(i, k) * a_i * x^(i - k).
+    ///
+    /// It is the k-th formal derivative divided by k!, so f(x + a) = sum_k D_k(f)(a) * x^k.
+    pub fn hasse_derivative(&self, k: usize) -> UnivariatePolynomialDense<F> {
+        if self.coefficients.len() <= k {
+            return UnivariatePolynomialDense::zero();
+        }
+        let mut binomial = F::one();
+        let mut coefficients = Vec::with_capacity(self.coefficients.len() - k);
+        for (i, coefficient) in self.coefficients.iter().enumerate().skip(k) {
+            if i > k {
+                // binomial(i, k) = binomial(i - 1, k) * i / (i - k)
+                binomial *= F::from(i as u64) * F::from((i - k) as u64).inverse().unwrap();
+            }
+            coefficients.push(binomial * coefficient);
+        }
+        UnivariatePolynomialDense::new(coefficients)
+    }
+
+    // the multiplicity of z as a root, i.e the number of leading zero coefficients of f(x + z)
+    pub fn root_multiplicity(&self, z: F) -> usize {
+        if self.is_zero() {
+            panic!("every point is a root of the zero polynomial");
+        }
+        self.shift(z)
+            .coefficients
+            .iter()
+            .take_while(|coefficient| coefficient.is_zero())
+            .count()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use ark_bn254::Fq;
+
+    fn poly(values: &[i64]) -> UnivariatePolynomialDense<Fq> {
+        UnivariatePolynomialDense::new(values.iter().map(|value| Fq::from(*value)).collect())
+    }
+
+    #[test]
+    fn test_compose() {
+        // f(x) = x^2 + 1, g(x) = 2x + 3, f(g(x)) = 4x^2 + 12x + 10
+        let f = poly(&[1, 0, 1]);
+        let g = poly(&[3, 2]);
+        assert_eq!(f.compose(&g), poly(&[10, 12, 4]));
+        assert_eq!(g.compose(&f), poly(&[5, 0, 2]));
+        assert_eq!(f.compose(&poly(&[7])), poly(&[50]));
+        assert!(UnivariatePolynomialDense::<Fq>::zero()
+            .compose(&g)
+            .is_zero());
+    }
+
+    #[test]
+    fn test_scale_and_shift() {
+        let f = poly(&[5, -1, 3, 2]);
+        let c = Fq::from(7);
+        assert_eq!(f.scale(c), f.compose(&poly(&[0, 7])));
+        assert_eq!(f.shift(c), f.compose(&poly(&[7, 1])));
+        assert_eq!(f.shift(c).evaluate(Fq::from(2)), f.evaluate(Fq::from(9)));
+        assert_eq!(f.shift(c).shift(-c), f);
+    }
+
+    #[test]
+    fn test_derivative() {
+        // d/dx (1 + 2x + 3x^2) = 2 + 6x
+        assert_eq!(poly(&[1, 2, 3]).derivative(), poly(&[2, 6]));
+        assert!(poly(&[4]).derivative().is_zero());
+    }
+
+    #[test]
+    fn test_hasse_derivative() {
+        let f = poly(&[1, 2, 3, 4, 5]);
+        assert_eq!(f.hasse_derivative(0), f);
+        assert_eq!(f.hasse_derivative(1), f.derivative());
+        // binomial(i, 2) * a_i = [3, 12, 30]
+        assert_eq!(f.hasse_derivative(2), poly(&[3, 12, 30]));
+        assert_eq!(f.hasse_derivative(4), poly(&[5]));
+        assert!(f.hasse_derivative(5).is_zero());
+
+        // the coefficients of f(x + a) are the Hasse derivatives at a
+        let a = Fq::from(3);
+        let shifted = f.shift(a);
+        for k in 0..5 {
+            assert_eq!(shifted.coefficients[k], f.hasse_derivative(k).evaluate(a));
+        }
+    }
+
+    #[test]
+    fn test_root_multiplicity() {
+        let f = &(&poly(&[-2, 1]) * &poly(&[-2, 1])) * &(&poly(&[-2, 1]) * &poly(&[1, 1]));
+        assert_eq!(f.root_multiplicity(Fq::from(2)), 3);
+        assert_eq!(f.root_multiplicity(-Fq::from(1)), 1);
+        assert_eq!(f.root_multiplicity(Fq::from(5)), 0);
+    }
+}
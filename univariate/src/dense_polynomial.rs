@@ -1,17 +1,39 @@
-use ark_ff::PrimeField;
+use crate::domain::Radix2Domain;
+use crate::parallel::{cfg_into_iter, cfg_iter_mut, join};
+use ark_ff::{PrimeField, Zero};
+#[cfg(feature = "parallel")]
+use rayon::prelude::*;
+
+// below this many coefficients in the smaller operand, schoolbook multiplication beats the FFT
+pub const FFT_MULTIPLICATION_THRESHOLD: usize = 32;
+
+// below this many coefficients in the smaller operand, Karatsuba recurses into schoolbook
+pub const KARATSUBA_THRESHOLD: usize = 16;
+
 /// A struct representing a univariate polynomial in dense form
+///
+/// The coefficients never carry zero leading terms, so `degree` is always the true degree.
+/// The zero polynomial has no coefficients and, by convention, degree 0.
+/// Outside the crate the fields are read through `degree()` and `coefficients()`, so only `new`
+/// and the operations here can build a polynomial.
+#[derive(Debug, Clone, PartialEq, Eq)]
 pub struct UnivariatePolynomialDense<F: PrimeField> {
-    pub degree: u64,
-    pub coefficients: Vec<F>,
+    pub(crate) degree: u64,
+    pub(crate) coefficients: Vec<F>,
 }
 
 impl<F: PrimeField> UnivariatePolynomialDense<F> {
     /// Creates a new `UnivariatePolynomialDense` with the given coefficients.
     ///
+    /// Zero leading coefficients are dropped.
+    ///
     /// # Arguments
     ///
     /// * `coefficients` - A vector of coefficients, where the i-th element is the coefficient for x^i.
-    pub fn new(coefficients: Vec<F>) -> Self {
+    pub fn new(mut coefficients: Vec<F>) -> Self {
+        while coefficients.last().is_some_and(|c| c.is_zero()) {
+            coefficients.pop();
+        }
         let degree: u64 = if coefficients.is_empty() {
             0
         } else {
@@ -23,6 +45,25 @@ impl<F: PrimeField> UnivariatePolynomialDense<F> {
         }
     }
 
+    pub fn degree(&self) -> u64 {
+        self.degree
+    }
+
+    // the coefficient of x^i at index i, with no zero leading terms
+    pub fn coefficients(&self) -> &[F] {
+        &self.coefficients
+    }
+
+    // returns true for the zero polynomial
+    pub fn is_zero(&self) -> bool {
+        self.coefficients.is_empty()
+    }
+
+    // returns the coefficient of the highest power of x, zero for the zero polynomial
+    pub fn leading_coefficient(&self) -> F {
+        self.coefficients.last().copied().unwrap_or(F::zero())
+    }
+
     // Evaluates the polynomial at the given point `x`.
     ///
     /// # Arguments
@@ -33,20 +74,11 @@ impl<F: PrimeField> UnivariatePolynomialDense<F> {
     ///
     /// The value of the polynomial at `x`.
     pub fn evaluate(&self, x: F) -> F {
-        // logic
-        let mut val: F = F::zero();
-        let mut x_pows:F = F::one();
-        for (i, value) in self.coefficients.iter().enumerate() {
-            if x == F::zero() {
-                return self.coefficients[0];
-            }
-            // [x^0, x^0 * x, x^1 * x, x^2 * x, …]
-            if i > 0{
-                x_pows *= x;
-            }
-            val += *value * x_pows;
-        }
-        val
+        // horner's method: a0 + x(a1 + x(a2 + ...))
+        self.coefficients
+            .iter()
+            .rev()
+            .fold(F::zero(), |acc, coefficient| acc * x + coefficient)
     }
 
     // polynomial addition
@@ -54,65 +86,127 @@ impl<F: PrimeField> UnivariatePolynomialDense<F> {
         &self,
         other: &UnivariatePolynomialDense<F>,
     ) -> UnivariatePolynomialDense<F> {
-        let mut origin_coefficients: Vec<F> = Vec::new();
-        let mut other_cofficients: Vec<F> = Vec::new();
-
-        let mut result_coefficients: Vec<F> = Vec::new();
-
-        let degree: u64;
-
-        if self.degree > other.degree {
-            origin_coefficients = self.coefficients.clone();
-            other_cofficients = other.coefficients.clone();
-            degree = self.degree; // degree of the result is the highest degree polynomial in the addition
+        let (longer, shorter) = if self.coefficients.len() >= other.coefficients.len() {
+            (&self.coefficients, &other.coefficients)
         } else {
-            origin_coefficients = other.coefficients.clone();
-            other_cofficients = self.coefficients.clone();
-            degree = other.degree; // degree of the result is the highest degree polynomial in the addition
-        }
-        for (i, value) in origin_coefficients.iter().enumerate() {
-            if i < other_cofficients.len() {
-                result_coefficients.push(*value + other_cofficients[i]);
-            } else {
-                result_coefficients.push(*value);
-            }
+            (&other.coefficients, &self.coefficients)
+        };
+        let mut result_coefficients = longer.clone();
+        for (result, value) in result_coefficients.iter_mut().zip(shorter) {
+            *result += value;
         }
-        UnivariatePolynomialDense {
-            degree,
-            coefficients: result_coefficients,
+        // the leading terms may cancel, new() restores the true degree
+        UnivariatePolynomialDense::new(result_coefficients)
+    }
+
+    // polynomial subtraction
+    pub fn polynomial_subtraction(
+        &self,
+        other: &UnivariatePolynomialDense<F>,
+    ) -> UnivariatePolynomialDense<F> {
+        let mut result_coefficients = self.coefficients.clone();
+        if result_coefficients.len() < other.coefficients.len() {
+            result_coefficients.resize(other.coefficients.len(), F::zero());
         }
+        for (result, value) in result_coefficients.iter_mut().zip(&other.coefficients) {
+            *result -= value;
+        }
+        UnivariatePolynomialDense::new(result_coefficients)
     }
 
     // polynomial multiplication
-
+    // uses the FFT when the field has a large enough radix-2 subgroup, and Karatsuba otherwise
+    // (e.g over BN254 Fq, whose two-adicity is 1), small products stay schoolbook
     pub fn polynomial_multiplication(
         &self,
         other: &UnivariatePolynomialDense<F>,
     ) -> UnivariatePolynomialDense<F> {
-        let m = self.coefficients.len();
-        let n = other.coefficients.len();
-        let no_of_coefficients = m + n - 1;
-        let mut prod_array: Vec<F> = vec![F::zero(); (no_of_coefficients) as usize];
-        for i in 0..m as usize {
-            for j in 0..n as usize {
-                prod_array[i + j] += self.coefficients[i] * other.coefficients[j];
+        if self.is_zero() || other.is_zero() {
+            return UnivariatePolynomialDense::new(Vec::new());
+        }
+        let smaller = self.coefficients.len().min(other.coefficients.len());
+        if smaller >= FFT_MULTIPLICATION_THRESHOLD {
+            if let Some(product) = self.fft_multiplication(other) {
+                return product;
             }
         }
+        if smaller >= KARATSUBA_THRESHOLD {
+            return self.karatsuba_multiplication(other);
+        }
+        self.schoolbook_multiplication(other)
+    }
+
+    // O(n * m) multiplication
+    pub fn schoolbook_multiplication(
+        &self,
+        other: &UnivariatePolynomialDense<F>,
+    ) -> UnivariatePolynomialDense<F> {
+        if self.is_zero() || other.is_zero() {
+            return UnivariatePolynomialDense::new(Vec::new());
+        }
+        let (a, b) = (&self.coefficients, &other.coefficients);
+        let no_of_coefficients = a.len() + b.len() - 1;
+        // every output coefficient is an independent sum of a_i * b_(k - i)
+        let prod_array: Vec<F> = cfg_into_iter!(0..no_of_coefficients)
+            .map(|k| {
+                let start = k.saturating_sub(b.len() - 1);
+                let end = k.min(a.len() - 1);
+                (start..=end).map(|i| a[i] * b[k - i]).sum()
+            })
+            .collect();
 
         UnivariatePolynomialDense::new(prod_array)
     }
 
+    /// Karatsuba multiplication in O(n^1.585) field multiplications, works over any field.
+    ///
+    /// Unbalanced operands are cut into chunks the size of the shorter one so every recursive
+    /// product stays balanced.
+    pub fn karatsuba_multiplication(
+        &self,
+        other: &UnivariatePolynomialDense<F>,
+    ) -> UnivariatePolynomialDense<F> {
+        if self.is_zero() || other.is_zero() {
+            return UnivariatePolynomialDense::new(Vec::new());
+        }
+        let mut product = vec![F::zero(); self.coefficients.len() + other.coefficients.len() - 1];
+        karatsuba(&self.coefficients, &other.coefficients, &mut product);
+        UnivariatePolynomialDense::new(product)
+    }
+
+    /// Multiplies two polynomials in O(n log n) by evaluating both over a radix-2 domain,
+    /// multiplying pointwise and interpolating back.
+    ///
+    /// Returns `None` when the field has no radix-2 subgroup large enough to hold the product.
+    pub fn fft_multiplication(
+        &self,
+        other: &UnivariatePolynomialDense<F>,
+    ) -> Option<UnivariatePolynomialDense<F>> {
+        if self.is_zero() || other.is_zero() {
+            return Some(UnivariatePolynomialDense::new(Vec::new()));
+        }
+        let no_of_coefficients = self.coefficients.len() + other.coefficients.len() - 1;
+        let domain = Radix2Domain::<F>::new(no_of_coefficients)?;
+
+        let (mut self_evals, other_evals) = join(
+            || domain.fft(&self.coefficients),
+            || domain.fft(&other.coefficients),
+        );
+        cfg_iter_mut!(self_evals)
+            .zip(cfg_into_iter!(other_evals))
+            .for_each(|(a, b)| *a *= b);
+        domain.ifft_in_place(&mut self_evals);
+        self_evals.truncate(no_of_coefficients);
+
+        Some(UnivariatePolynomialDense::new(self_evals))
+    }
+
     // polynomial scalar multiplication
 
     pub fn scalar_multiplication(&self, scalar: F) -> UnivariatePolynomialDense<F> {
-        let mut product: Vec<F> = Vec::new();
-        for val in self.coefficients.iter() {
-            product.push(*val * (scalar as F))
-        }
-        UnivariatePolynomialDense {
-            degree: self.degree,
-            coefficients: product,
-        }
+        let product: Vec<F> = self.coefficients.iter().map(|val| *val * scalar).collect();
+        // multiplying by zero gives the zero polynomial
+        UnivariatePolynomialDense::new(product)
     }
 
     pub fn lagrange_basis(
@@ -138,9 +232,7 @@ impl<F: PrimeField> UnivariatePolynomialDense<F> {
         // get the inverse of the denominator
         // multiply by the y_value
 
-        let basis_poly = basis_poly_numerator.scalar_multiplication(y_value / denominator);
-
-        basis_poly
+        basis_poly_numerator.scalar_multiplication(y_value / denominator)
     }
 
     // y_values -> [x0,x1,x2 ..., xn] [y0,y1,y2, ...yn]
@@ -150,8 +242,7 @@ impl<F: PrimeField> UnivariatePolynomialDense<F> {
             panic!("The number of x values must be equal to the number of y values");
         }
         // polyniomial sum :: but we set it to the zero polynomial at first or additive identity
-        let mut polynomial_sum: UnivariatePolynomialDense<F> =
-            UnivariatePolynomialDense::new(vec![F::zero()]);
+        let mut polynomial_sum: UnivariatePolynomialDense<F> = UnivariatePolynomialDense::zero();
 
         for (i, x) in x_values.iter().enumerate() {
             let single_basis_poly =
@@ -162,13 +253,84 @@ impl<F: PrimeField> UnivariatePolynomialDense<F> {
         polynomial_sum
     }
 
+    // interpolates evaluations over a radix-2 domain in O(n log n)
+    // evaluations[i] is the value at domain.element(i)
+    pub fn interpolate_over_domain(
+        domain: &Radix2Domain<F>,
+        evaluations: &[F],
+    ) -> UnivariatePolynomialDense<F> {
+        UnivariatePolynomialDense::new(domain.ifft(evaluations))
+    }
+
+    // evaluates the polynomial at every element of a radix-2 domain in O(n log n)
+    pub fn evaluate_over_domain(&self, domain: &Radix2Domain<F>) -> Vec<F> {
+        domain.fft(&self.coefficients)
+    }
+}
+
+// adds a * b into out, where out has at least a.len() + b.len() - 1 slots and both inputs are
+// non-empty
+fn karatsuba<F: PrimeField>(a: &[F], b: &[F], out: &mut [F]) {
+    let (a, b) = if a.len() >= b.len() { (a, b) } else { (b, a) };
+    if b.len() < KARATSUBA_THRESHOLD {
+        for (i, x) in a.iter().enumerate() {
+            for (j, y) in b.iter().enumerate() {
+                out[i + j] += *x * y;
+            }
+        }
+        return;
+    }
+    // unbalanced: multiply each b-sized chunk of a by b
+    if b.len() <= a.len() / 2 {
+        for (k, chunk) in a.chunks(b.len()).enumerate() {
+            karatsuba(chunk, b, &mut out[k * b.len()..]);
+        }
+        return;
+    }
+
+    // a = a0 + x^m a1, b = b0 + x^m b1 with b1 non-empty since b.len() > m
+    let m = a.len() / 2;
+    let (a0, a1) = a.split_at(m);
+    let (b0, b1) = b.split_at(m);
+
+    let multiply = |x: &[F], y: &[F]| -> Vec<F> {
+        let mut product = vec![F::zero(); x.len() + y.len() - 1];
+        karatsuba(x, y, &mut product);
+        product
+    };
+    let add = |x: &[F], y: &[F]| -> Vec<F> {
+        let mut sum = x.to_vec();
+        sum.resize(x.len().max(y.len()), F::zero());
+        for (s, value) in sum.iter_mut().zip(y) {
+            *s += value;
+        }
+        sum
+    };
+    // the three half-size products are independent
+    let ((z0, z2), mut z1) = join(
+        || join(|| multiply(a0, b0), || multiply(a1, b1)),
+        || multiply(&add(a0, a1), &add(b0, b1)),
+    );
+
+    // z1 - z0 - z2 is the middle term
+    for (i, value) in z0.iter().enumerate() {
+        out[i] += value;
+        z1[i] -= value;
+    }
+    for (i, value) in z2.iter().enumerate() {
+        out[i + 2 * m] += value;
+        z1[i] -= value;
+    }
+    for (i, value) in z1.iter().enumerate() {
+        out[i + m] += value;
+    }
 }
 
 // write tests
 #[cfg(test)]
 mod tests {
     use super::*;
-    use ark_bn254::Fq;
+    use ark_bn254::{Fq, Fr};
 
     fn poly1() -> UnivariatePolynomialDense<Fq> {
         UnivariatePolynomialDense::new(vec![Fq::from(1), Fq::from(2), Fq::from(3)])
@@ -185,6 +347,11 @@ mod tests {
             poly.coefficients,
             vec![Fq::from(1), Fq::from(2), Fq::from(3)]
         );
+
+        // the accessors see the trimmed coefficients
+        let trimmed = UnivariatePolynomialDense::new(vec![Fq::from(4), Fq::from(0)]);
+        assert_eq!(trimmed.degree(), 0);
+        assert_eq!(trimmed.coefficients(), &[Fq::from(4)]);
     }
 
     #[test]
@@ -194,7 +361,7 @@ mod tests {
     }
 
     #[test]
-    fn test_evaluate2(){
+    fn test_evaluate2() {
         let poly = UnivariatePolynomialDense::new(vec![Fq::from(5), Fq::from(0), Fq::from(3)]);
         assert_eq!(poly.evaluate(Fq::from(2)), Fq::from(17));
     }
@@ -246,4 +413,71 @@ mod tests {
             vec![Fq::from(2), Fq::from(0), Fq::from(2)]
         );
     }
+
+    #[test]
+    fn test_fft_multiplication() {
+        let poly_1 = UnivariatePolynomialDense::new((0..50).map(|i| Fr::from(i * 7 + 1)).collect());
+        let poly_2 = UnivariatePolynomialDense::new((0..40).map(|i| Fr::from(i * 3 + 2)).collect());
+        let fft_product = poly_1.fft_multiplication(&poly_2).unwrap();
+        assert_eq!(fft_product.degree, 88);
+        assert_eq!(
+            fft_product.coefficients,
+            poly_1.schoolbook_multiplication(&poly_2).coefficients
+        );
+        assert_eq!(
+            poly_1.polynomial_multiplication(&poly_2).coefficients,
+            fft_product.coefficients
+        );
+    }
+
+    #[test]
+    fn test_fft_multiplication_unsupported_field() {
+        // BN254 Fq has no radix-2 subgroup larger than 2, so we fall back to Karatsuba
+        let poly_1 = UnivariatePolynomialDense::new((0..40).map(|i| Fq::from(i + 1)).collect());
+        let poly_2 = UnivariatePolynomialDense::new((0..40).map(|i| Fq::from(i + 2)).collect());
+        assert!(poly_1.fft_multiplication(&poly_2).is_none());
+        assert_eq!(
+            poly_1.polynomial_multiplication(&poly_2).coefficients,
+            poly_1.schoolbook_multiplication(&poly_2).coefficients
+        );
+    }
+
+    #[test]
+    fn test_karatsuba_multiplication() {
+        for (n, m) in [
+            (1, 1),
+            (16, 16),
+            (17, 33),
+            (100, 57),
+            (200, 20),
+            (129, 128),
+            (5, 300),
+        ] {
+            let poly_1 = UnivariatePolynomialDense::new(
+                (0..n).map(|i| Fq::from(i * i + 3) - Fq::from(n)).collect(),
+            );
+            let poly_2 =
+                UnivariatePolynomialDense::new((0..m).map(|i| Fq::from(7 * i + 1)).collect());
+            assert_eq!(
+                poly_1.karatsuba_multiplication(&poly_2),
+                poly_1.schoolbook_multiplication(&poly_2)
+            );
+        }
+        let zero = UnivariatePolynomialDense::<Fq>::new(Vec::new());
+        assert!(poly1().karatsuba_multiplication(&zero).is_zero());
+    }
+
+    #[test]
+    fn test_interpolate_over_domain() {
+        let domain = Radix2Domain::<Fr>::new(8).unwrap();
+        let poly = UnivariatePolynomialDense::new((0..8).map(|i| Fr::from(i + 5)).collect());
+        let evaluations = poly.evaluate_over_domain(&domain);
+        for (i, x) in domain.elements().iter().enumerate() {
+            assert_eq!(evaluations[i], poly.evaluate(*x));
+        }
+        assert_eq!(
+            UnivariatePolynomialDense::interpolate_over_domain(&domain, &evaluations).coefficients,
+            poly.coefficients
+        );
+    }
 }
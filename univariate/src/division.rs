@@ -0,0 +1,396 @@
+use crate::dense_polynomial::UnivariatePolynomialDense;
+use crate::domain::Radix2Domain;
+use ark_ff::{PrimeField, Zero};
+use std::fmt;
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum DivisionError {
+    // the divisor is the zero polynomial
+    DivisionByZero,
+    // the division was required to be exact but left a remainder
+    NonZeroRemainder,
+}
+
+impl fmt::Display for DivisionError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            DivisionError::DivisionByZero => write!(f, "division by the zero polynomial"),
+            DivisionError::NonZeroRemainder => write!(f, "division left a non-zero remainder"),
+        }
+    }
+}
+
+impl std::error::Error for DivisionError {}
+
+// below this many coefficients in the quotient or divisor, long division beats Newton iteration
+pub const NEWTON_DIVISION_THRESHOLD: usize = 64;
+
+impl<F: PrimeField> UnivariatePolynomialDense<F> {
+    /// Polynomial division: returns `(quotient, remainder)` such that
+    /// `self = quotient * divisor + remainder` and `deg(remainder) < deg(divisor)`.
+    ///
+    /// Small divisions use schoolbook long division, large ones use Newton iteration so the
+    /// cost is a constant number of polynomial multiplications.
+    pub fn div_rem(
+        &self,
+        divisor: &UnivariatePolynomialDense<F>,
+    ) -> Result<(UnivariatePolynomialDense<F>, UnivariatePolynomialDense<F>), DivisionError> {
+        if divisor.is_zero() {
+            return Err(DivisionError::DivisionByZero);
+        }
+        let divisor_degree = divisor.degree as usize;
+        if self.coefficients.len() <= divisor_degree {
+            return Ok((UnivariatePolynomialDense::zero(), self.clone()));
+        }
+        let quotient_len = self.coefficients.len() - divisor_degree;
+        if quotient_len.min(divisor_degree) >= NEWTON_DIVISION_THRESHOLD {
+            Ok(self.newton_div_rem(divisor))
+        } else {
+            Ok(self.long_div_rem(divisor))
+        }
+    }
+
+    // schoolbook long division, O(deg(quotient) * deg(divisor))
+    // expects a non-zero divisor of degree at most deg(self)
+    fn long_div_rem(
+        &self,
+        divisor: &UnivariatePolynomialDense<F>,
+    ) -> (UnivariatePolynomialDense<F>, UnivariatePolynomialDense<F>) {
+        let divisor_coefficients = &divisor.coefficients;
+        let divisor_degree = divisor.degree as usize;
+        let mut remainder = self.coefficients.clone();
+
+        let leading_inverse = divisor.leading_coefficient().inverse().unwrap();
+        let mut quotient = vec![F::zero(); remainder.len() - divisor_degree];
+        // cancel the leading term of the remainder, from the highest power down
+        for i in (0..quotient.len()).rev() {
+            let coefficient = remainder[i + divisor_degree] * leading_inverse;
+            quotient[i] = coefficient;
+            for (j, divisor_coefficient) in divisor_coefficients.iter().enumerate() {
+                remainder[i + j] -= coefficient * divisor_coefficient;
+            }
+        }
+        remainder.truncate(divisor_degree);
+
+        (
+            UnivariatePolynomialDense::new(quotient),
+            UnivariatePolynomialDense::new(remainder),
+        )
+    }
+
+    // division via reversal: with m = deg(self), n = deg(divisor) and rev_k(p) = x^k * p(1/x),
+    // rev_(m-n)(quotient) = rev_m(self) * rev_n(divisor)^-1 mod x^(m-n+1)
+    // expects a non-zero divisor of degree at most deg(self)
+    fn newton_div_rem(
+        &self,
+        divisor: &UnivariatePolynomialDense<F>,
+    ) -> (UnivariatePolynomialDense<F>, UnivariatePolynomialDense<F>) {
+        let divisor_degree = divisor.degree as usize;
+        let quotient_len = self.coefficients.len() - divisor_degree;
+
+        let reversed_divisor =
+            UnivariatePolynomialDense::new(divisor.coefficients.iter().rev().copied().collect());
+        let mut reversed_dividend: Vec<F> = self.coefficients.iter().rev().copied().collect();
+        reversed_dividend.truncate(quotient_len);
+
+        let inverse = reversed_divisor.inverse_mod_x_pow(quotient_len);
+        let mut reversed_quotient = UnivariatePolynomialDense::new(reversed_dividend)
+            .polynomial_multiplication(&inverse)
+            .coefficients;
+        reversed_quotient.resize(quotient_len, F::zero());
+        reversed_quotient.reverse();
+        let quotient = UnivariatePolynomialDense::new(reversed_quotient);
+
+        let mut remainder = self
+            .polynomial_subtraction(&quotient.polynomial_multiplication(divisor))
+            .coefficients;
+        remainder.truncate(divisor_degree);
+        (quotient, UnivariatePolynomialDense::new(remainder))
+    }
+
+    /// Returns h such that self * h = 1 mod x^k, computed by Newton iteration
+    /// h <- h * (2 - self * h), doubling the precision every step.
+    ///
+    /// Panics if the constant term of self is zero, since self is then not invertible.
+    pub fn inverse_mod_x_pow(&self, k: usize) -> UnivariatePolynomialDense<F> {
+        let constant_term = self.coefficients.first().copied().unwrap_or(F::zero());
+        let constant_inverse = match constant_term.inverse() {
+            Some(inverse) => inverse,
+            None => panic!("polynomial with a zero constant term is not invertible mod x^k"),
+        };
+        if k == 0 {
+            return UnivariatePolynomialDense::zero();
+        }
+
+        let two = UnivariatePolynomialDense::new(vec![F::from(2u64)]);
+        let mut inverse = UnivariatePolynomialDense::new(vec![constant_inverse]);
+        let mut precision = 1;
+        while precision < k {
+            precision = (2 * precision).min(k);
+            let product = self
+                .truncate(precision)
+                .polynomial_multiplication(&inverse)
+                .truncate(precision);
+            inverse = inverse
+                .polynomial_multiplication(&two.polynomial_subtraction(&product))
+                .truncate(precision);
+        }
+        inverse
+    }
+
+    // returns self mod x^k, i.e the first k coefficients
+    pub fn truncate(&self, k: usize) -> UnivariatePolynomialDense<F> {
+        UnivariatePolynomialDense::new(self.coefficients.iter().take(k).copied().collect())
+    }
+
+    // returns only the quotient of the long division
+    pub fn div(
+        &self,
+        divisor: &UnivariatePolynomialDense<F>,
+    ) -> Result<UnivariatePolynomialDense<F>, DivisionError> {
+        self.div_rem(divisor).map(|(quotient, _)| quotient)
+    }
+
+    // returns only the remainder of the long division i.e self mod divisor
+    pub fn rem(
+        &self,
+        divisor: &UnivariatePolynomialDense<F>,
+    ) -> Result<UnivariatePolynomialDense<F>, DivisionError> {
+        self.div_rem(divisor).map(|(_, remainder)| remainder)
+    }
+
+    // divides by `divisor`, failing if it does not divide self exactly
+    pub fn divide_exact(
+        &self,
+        divisor: &UnivariatePolynomialDense<F>,
+    ) -> Result<UnivariatePolynomialDense<F>, DivisionError> {
+        let (quotient, remainder) = self.div_rem(divisor)?;
+        if remainder.is_zero() {
+            Ok(quotient)
+        } else {
+            Err(DivisionError::NonZeroRemainder)
+        }
+    }
+
+    /// Synthetic (Ruffini) division by the linear factor `(x - z)` in O(n).
+    ///
+    /// Returns `(quotient, remainder)` where the remainder is `self(z)`, so the KZG opening
+    /// quotient `(f(x) - f(z)) / (x - z)` is just the quotient returned here.
+    pub fn divide_by_linear(&self, z: F) -> (UnivariatePolynomialDense<F>, F) {
+        let coefficients = &self.coefficients;
+        if coefficients.is_empty() {
+            return (UnivariatePolynomialDense::zero(), F::zero());
+        }
+        let mut quotient = vec![F::zero(); coefficients.len() - 1];
+        let mut acc = F::zero();
+        for i in (0..coefficients.len()).rev() {
+            acc = acc * z + coefficients[i];
+            if i > 0 {
+                quotient[i - 1] = acc;
+            }
+        }
+        (UnivariatePolynomialDense::new(quotient), acc)
+    }
+
+    /// Exact division by the sparse polynomial `x^n - c` in O(deg(self)).
+    ///
+    /// Fails with `DivisionError::NonZeroRemainder` instead of silently dropping the remainder,
+    /// e.g. when a STARK constraint does not actually vanish over the trace domain.
+    pub fn divide_by_vanishing(
+        &self,
+        n: usize,
+        c: F,
+    ) -> Result<UnivariatePolynomialDense<F>, DivisionError> {
+        if n == 0 {
+            // x^0 - c is the constant 1 - c
+            let constant = UnivariatePolynomialDense::new(vec![F::one() - c]);
+            return self.divide_exact(&constant);
+        }
+        if self.coefficients.len() <= n {
+            return if self.is_zero() {
+                Ok(UnivariatePolynomialDense::zero())
+            } else {
+                Err(DivisionError::NonZeroRemainder)
+            };
+        }
+
+        // x^i = x^(i - n) * (x^n - c) + c * x^(i - n)
+        let mut remainder = self.coefficients.clone();
+        let mut quotient = vec![F::zero(); remainder.len() - n];
+        for i in (n..remainder.len()).rev() {
+            let coefficient = remainder[i];
+            quotient[i - n] = coefficient;
+            remainder[i - n] += c * coefficient;
+        }
+        if remainder[..n]
+            .iter()
+            .any(|coefficient| !coefficient.is_zero())
+        {
+            return Err(DivisionError::NonZeroRemainder);
+        }
+        Ok(UnivariatePolynomialDense::new(quotient))
+    }
+
+    // exact division by the vanishing polynomial of a radix-2 domain (or coset)
+    pub fn divide_by_vanishing_polynomial(
+        &self,
+        domain: &Radix2Domain<F>,
+    ) -> Result<UnivariatePolynomialDense<F>, DivisionError> {
+        self.divide_by_vanishing(domain.size, domain.offset_pow_size)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use ark_bn254::{Fq, Fr};
+
+    fn to_fq(values: &[i64]) -> Vec<Fq> {
+        values.iter().map(|value| Fq::from(*value)).collect()
+    }
+
+    #[test]
+    fn test_div_rem() {
+        // x^3 + 2x^2 + 3x + 4 = (x + 1)(x^2 + x + 2) + 2
+        let dividend = UnivariatePolynomialDense::new(to_fq(&[4, 3, 2, 1]));
+        let divisor = UnivariatePolynomialDense::new(to_fq(&[1, 1]));
+        let (quotient, remainder) = dividend.div_rem(&divisor).unwrap();
+        assert_eq!(quotient.coefficients, to_fq(&[2, 1, 1]));
+        assert_eq!(remainder.coefficients, to_fq(&[2]));
+    }
+
+    #[test]
+    fn test_div_rem_reconstructs_dividend() {
+        let dividend = UnivariatePolynomialDense::new(to_fq(&[7, 0, 5, 11, 3, 9]));
+        let divisor = UnivariatePolynomialDense::new(to_fq(&[2, 0, 3]));
+        let (quotient, remainder) = dividend.div_rem(&divisor).unwrap();
+        assert!(remainder.degree < divisor.degree);
+        assert_eq!(
+            quotient
+                .polynomial_multiplication(&divisor)
+                .polynomial_addition(&remainder)
+                .coefficients,
+            dividend.coefficients
+        );
+    }
+
+    #[test]
+    fn test_newton_division_matches_long_division() {
+        let dividend =
+            UnivariatePolynomialDense::new((0..300).map(|i| Fr::from(i * i + 7)).collect());
+        let divisor =
+            UnivariatePolynomialDense::new((0..120).map(|i| Fr::from(3 * i + 1)).collect());
+        let (quotient, remainder) = dividend.newton_div_rem(&divisor);
+        let (expected_quotient, expected_remainder) = dividend.long_div_rem(&divisor);
+        assert_eq!(quotient, expected_quotient);
+        assert_eq!(remainder, expected_remainder);
+        assert_eq!(dividend.div_rem(&divisor).unwrap(), (quotient, remainder));
+    }
+
+    #[test]
+    fn test_inverse_mod_x_pow() {
+        // 1 / (1 - x) = 1 + x + x^2 + ...
+        let poly = UnivariatePolynomialDense::new(to_fq(&[1, -1]));
+        assert_eq!(
+            poly.inverse_mod_x_pow(5).coefficients,
+            to_fq(&[1, 1, 1, 1, 1])
+        );
+
+        let poly = UnivariatePolynomialDense::new(to_fq(&[3, 5, 0, 2, 9]));
+        let inverse = poly.inverse_mod_x_pow(7);
+        assert_eq!(
+            poly.polynomial_multiplication(&inverse).truncate(7),
+            UnivariatePolynomialDense::new(to_fq(&[1]))
+        );
+    }
+
+    #[test]
+    fn test_div_rem_smaller_dividend() {
+        let dividend = UnivariatePolynomialDense::new(to_fq(&[1, 2]));
+        let divisor = UnivariatePolynomialDense::new(to_fq(&[1, 2, 3]));
+        let (quotient, remainder) = dividend.div_rem(&divisor).unwrap();
+        assert!(quotient.is_zero());
+        assert_eq!(remainder.coefficients, to_fq(&[1, 2]));
+    }
+
+    #[test]
+    fn test_division_by_zero() {
+        let dividend = UnivariatePolynomialDense::new(to_fq(&[1, 2]));
+        let zero = UnivariatePolynomialDense::new(to_fq(&[0, 0]));
+        assert_eq!(
+            dividend.div_rem(&zero).err(),
+            Some(DivisionError::DivisionByZero)
+        );
+    }
+
+    #[test]
+    fn test_divide_exact() {
+        // (x - 1)(x - 2) = x^2 - 3x + 2
+        let product = UnivariatePolynomialDense::new(to_fq(&[2, -3, 1]));
+        let factor = UnivariatePolynomialDense::new(to_fq(&[-1, 1]));
+        assert_eq!(
+            product.divide_exact(&factor).unwrap().coefficients,
+            to_fq(&[-2, 1])
+        );
+        let not_a_factor = UnivariatePolynomialDense::new(to_fq(&[-3, 1]));
+        assert_eq!(
+            product.divide_exact(&not_a_factor).err(),
+            Some(DivisionError::NonZeroRemainder)
+        );
+    }
+
+    #[test]
+    fn test_divide_by_linear() {
+        let poly = UnivariatePolynomialDense::new(to_fq(&[4, 3, 2, 1]));
+        let z = Fq::from(5);
+        let (quotient, remainder) = poly.divide_by_linear(z);
+        assert_eq!(remainder, poly.evaluate(z));
+
+        let linear = UnivariatePolynomialDense::new(vec![-z, Fq::from(1)]);
+        let (expected_quotient, _) = poly.div_rem(&linear).unwrap();
+        assert_eq!(quotient.coefficients, expected_quotient.coefficients);
+    }
+
+    #[test]
+    fn test_kzg_opening_quotient() {
+        // q(x) = (f(x) - f(z)) / (x - z)
+        let poly = UnivariatePolynomialDense::new(to_fq(&[1, 2, 3]));
+        let z = Fq::from(2);
+        let numerator =
+            poly.polynomial_addition(&UnivariatePolynomialDense::new(vec![-poly.evaluate(z)]));
+        let linear = UnivariatePolynomialDense::new(vec![-z, Fq::from(1)]);
+        let quotient = numerator.divide_exact(&linear).unwrap();
+        assert_eq!(quotient.coefficients, to_fq(&[8, 3]));
+    }
+
+    #[test]
+    fn test_divide_by_vanishing() {
+        let domain = Radix2Domain::<Fr>::new_coset(4, Fr::from(3)).unwrap();
+        let poly = UnivariatePolynomialDense::new(vec![Fr::from(1), Fr::from(2), Fr::from(3)]);
+        let product = poly.polynomial_multiplication(&domain.vanishing_polynomial());
+
+        let quotient = product.divide_by_vanishing_polynomial(&domain).unwrap();
+        assert_eq!(quotient.coefficients, poly.coefficients);
+
+        let not_divisible =
+            product.polynomial_addition(&UnivariatePolynomialDense::new(vec![Fr::from(1)]));
+        assert_eq!(
+            not_divisible.divide_by_vanishing_polynomial(&domain).err(),
+            Some(DivisionError::NonZeroRemainder)
+        );
+    }
+
+    #[test]
+    fn test_divide_by_vanishing_matches_div_rem() {
+        let poly = UnivariatePolynomialDense::new(to_fq(&[6, 0, 0, 1, 5, 0, 2]));
+        let vanishing = UnivariatePolynomialDense::new(to_fq(&[-7, 0, 0, 1]));
+        let product = poly.polynomial_multiplication(&vanishing);
+        assert_eq!(
+            product
+                .divide_by_vanishing(3, Fq::from(7))
+                .unwrap()
+                .coefficients,
+            product.div(&vanishing).unwrap().coefficients
+        );
+    }
+}
@@ -0,0 +1,314 @@
+use crate::dense_polynomial::UnivariatePolynomialDense;
+use crate::parallel::cfg_chunks_mut;
+use crate::sparse_polynomial::UnivariatePolynomialSparse;
+use ark_ff::{FftField, PrimeField};
+#[cfg(feature = "parallel")]
+use rayon::prelude::*;
+
+/// A radix-2 evaluation domain: the multiplicative subgroup of size 2^k generated by a primitive
+/// root of unity `ω`, optionally shifted to the coset `offset · <ω>`.
+///
+/// The i-th element of the domain is `offset · ω^i`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Radix2Domain<F: FftField> {
+    pub size: usize,
+    pub log_size: u32,
+    pub generator: F,
+    pub generator_inv: F,
+    pub size_inv: F,
+    pub offset: F,
+    pub offset_inv: F,
+    // offset^size, the constant term of the vanishing polynomial x^size - offset^size
+    pub offset_pow_size: F,
+}
+
+impl<F: FftField> Radix2Domain<F> {
+    /// Creates the smallest radix-2 subgroup with at least `num_coefficients` elements.
+    ///
+    /// Returns `None` when the field has no subgroup of that size, e.g. when the size exceeds
+    /// `2^TWO_ADICITY` (BN254 `Fq` only supports domains of size 1 and 2).
+    pub fn new(num_coefficients: usize) -> Option<Self> {
+        let size = num_coefficients.max(1).checked_next_power_of_two()?;
+        let log_size = size.trailing_zeros();
+        if log_size > F::TWO_ADICITY {
+            return None;
+        }
+        let generator = F::get_root_of_unity(size as u64)?;
+        Some(Self {
+            size,
+            log_size,
+            generator,
+            generator_inv: generator.inverse()?,
+            size_inv: F::from(size as u64).inverse()?,
+            offset: F::one(),
+            offset_inv: F::one(),
+            offset_pow_size: F::one(),
+        })
+    }
+
+    /// Creates the coset `offset · <ω>` of the smallest radix-2 subgroup with at least
+    /// `num_coefficients` elements. `offset` must be non-zero.
+    pub fn new_coset(num_coefficients: usize, offset: F) -> Option<Self> {
+        Self::new(num_coefficients)?.get_coset(offset)
+    }
+
+    /// Returns the coset `offset · <ω>` of this domain's subgroup.
+    pub fn get_coset(&self, offset: F) -> Option<Self> {
+        Some(Self {
+            offset,
+            offset_inv: offset.inverse()?,
+            offset_pow_size: offset.pow([self.size as u64]),
+            ..*self
+        })
+    }
+
+    pub fn is_coset(&self) -> bool {
+        !self.offset.is_one()
+    }
+
+    // returns offset * ω^i
+    pub fn element(&self, i: usize) -> F {
+        self.offset * self.generator.pow([i as u64])
+    }
+
+    // returns [offset, offset * ω, offset * ω^2, ..., offset * ω^(n - 1)]
+    pub fn elements(&self) -> Vec<F> {
+        let mut elements = Vec::with_capacity(self.size);
+        let mut current = self.offset;
+        for _ in 0..self.size {
+            elements.push(current);
+            current *= self.generator;
+        }
+        elements
+    }
+
+    // evaluates Z(x) = x^n - offset^n at `x`
+    pub fn evaluate_vanishing_polynomial(&self, x: F) -> F {
+        x.pow([self.size as u64]) - self.offset_pow_size
+    }
+
+    /// Evaluates the polynomial with the given coefficients over the domain.
+    ///
+    /// `coefficients` may be shorter than the domain (it is zero padded) but not longer.
+    pub fn fft(&self, coefficients: &[F]) -> Vec<F> {
+        let mut values = coefficients.to_vec();
+        self.fft_in_place(&mut values);
+        values
+    }
+
+    pub fn fft_in_place(&self, coefficients: &mut Vec<F>) {
+        if coefficients.len() > self.size {
+            panic!(
+                "cannot evaluate {} coefficients over a domain of size {}",
+                coefficients.len(),
+                self.size
+            );
+        }
+        coefficients.resize(self.size, F::zero());
+        if self.is_coset() {
+            distribute_powers(coefficients, self.offset);
+        }
+        radix2_fft_in_place(coefficients, self.generator, self.log_size);
+    }
+
+    /// Recovers the coefficients of the unique polynomial of degree < n that takes the given
+    /// values over the domain.
+    pub fn ifft(&self, evaluations: &[F]) -> Vec<F> {
+        let mut values = evaluations.to_vec();
+        self.ifft_in_place(&mut values);
+        values
+    }
+
+    pub fn ifft_in_place(&self, evaluations: &mut [F]) {
+        if evaluations.len() != self.size {
+            panic!(
+                "expected {} evaluations, got {}",
+                self.size,
+                evaluations.len()
+            );
+        }
+        radix2_fft_in_place(evaluations, self.generator_inv, self.log_size);
+        for value in evaluations.iter_mut() {
+            *value *= self.size_inv;
+        }
+        if self.is_coset() {
+            distribute_powers(evaluations, self.offset_inv);
+        }
+    }
+}
+
+impl<F: PrimeField> Radix2Domain<F> {
+    // returns Z(x) = x^n - offset^n, the polynomial that is zero on every element of the domain
+    pub fn vanishing_polynomial(&self) -> UnivariatePolynomialDense<F> {
+        let mut coefficients = vec![F::zero(); self.size + 1];
+        coefficients[0] = -self.offset_pow_size;
+        coefficients[self.size] = F::one();
+        UnivariatePolynomialDense::new(coefficients)
+    }
+
+    // the same Z(x) with only its two non-zero terms stored
+    pub fn sparse_vanishing_polynomial(&self) -> UnivariatePolynomialSparse<F> {
+        UnivariatePolynomialSparse::new(vec![
+            (self.size as u64, F::one()),
+            (0, -self.offset_pow_size),
+        ])
+    }
+}
+
+// helper functions
+
+// multiplies the i-th value by g^i
+fn distribute_powers<F: FftField>(values: &mut [F], g: F) {
+    let mut pow = F::one();
+    for value in values.iter_mut() {
+        *value *= pow;
+        pow *= g;
+    }
+}
+
+// iterative Cooley-Tukey radix-2 FFT, `omega` must be a primitive 2^log_size-th root of unity
+fn radix2_fft_in_place<F: FftField>(values: &mut [F], omega: F, log_size: u32) {
+    let n = values.len();
+    bit_reverse_permutation(values);
+
+    let mut half = 1;
+    for _ in 0..log_size {
+        // primitive (2 * half)-th root of unity for this stage
+        let stage_root = omega.pow([(n / (2 * half)) as u64]);
+        let mut twiddles = Vec::with_capacity(half);
+        let mut w = F::one();
+        for _ in 0..half {
+            twiddles.push(w);
+            w *= stage_root;
+        }
+
+        cfg_chunks_mut!(values, 2 * half).for_each(|chunk| {
+            let (left, right) = chunk.split_at_mut(half);
+            for ((l, r), twiddle) in left.iter_mut().zip(right.iter_mut()).zip(&twiddles) {
+                let t = *r * twiddle;
+                *r = *l - t;
+                *l += t;
+            }
+        });
+        half *= 2;
+    }
+}
+
+// reverses the lowest `bits` bits of `index`
+// e.g for bits = 3 -> 001 becomes 100
+pub fn bit_reverse(index: usize, bits: u32) -> usize {
+    if bits == 0 {
+        return 0;
+    }
+    index.reverse_bits() >> (usize::BITS - bits)
+}
+
+// reorders a slice of length 2^k so that the element at i moves to bit_reverse(i, k)
+pub fn bit_reverse_permutation<T>(values: &mut [T]) {
+    let n = values.len();
+    if n <= 1 {
+        return;
+    }
+    if !n.is_power_of_two() {
+        panic!("{} is not a power of 2; Invalid length", n);
+    }
+    let bits = n.trailing_zeros();
+    for i in 0..n {
+        let j = bit_reverse(i, bits);
+        if i < j {
+            values.swap(i, j);
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use ark_bn254::{Fq, Fr};
+    use ark_ff::Field;
+
+    fn naive_evaluations(coefficients: &[Fr], domain: &Radix2Domain<Fr>) -> Vec<Fr> {
+        let poly = UnivariatePolynomialDense::new(coefficients.to_vec());
+        domain
+            .elements()
+            .iter()
+            .map(|x| poly.evaluate(*x))
+            .collect()
+    }
+
+    #[test]
+    fn test_domain_creation() {
+        let domain = Radix2Domain::<Fr>::new(5).unwrap();
+        assert_eq!(domain.size, 8);
+        assert_eq!(domain.log_size, 3);
+        assert_eq!(domain.generator.pow([8u64]), Fr::from(1));
+        assert_ne!(domain.generator.pow([4u64]), Fr::from(1));
+        assert_eq!(domain.generator * domain.generator_inv, Fr::from(1));
+        assert_eq!(domain.size_inv * Fr::from(8), Fr::from(1));
+    }
+
+    #[test]
+    fn test_domain_unsupported_size() {
+        // BN254 Fq has a two-adicity of 1
+        assert!(Radix2Domain::<Fq>::new(2).is_some());
+        assert!(Radix2Domain::<Fq>::new(4).is_none());
+    }
+
+    #[test]
+    fn test_bit_reverse_permutation() {
+        assert_eq!(bit_reverse(1, 3), 4);
+        assert_eq!(bit_reverse(6, 3), 3);
+        let mut values = vec![0, 1, 2, 3, 4, 5, 6, 7];
+        bit_reverse_permutation(&mut values);
+        assert_eq!(values, vec![0, 4, 2, 6, 1, 5, 3, 7]);
+    }
+
+    #[test]
+    fn test_fft_matches_naive_evaluation() {
+        let domain = Radix2Domain::<Fr>::new(8).unwrap();
+        let coefficients: Vec<Fr> = (1..=6).map(|i| Fr::from(i as u64)).collect();
+        assert_eq!(
+            domain.fft(&coefficients),
+            naive_evaluations(&coefficients, &domain)
+        );
+    }
+
+    #[test]
+    fn test_fft_ifft_roundtrip() {
+        let domain = Radix2Domain::<Fr>::new(16).unwrap();
+        let coefficients: Vec<Fr> = (0..16).map(|i| Fr::from(i * i + 3)).collect();
+        assert_eq!(domain.ifft(&domain.fft(&coefficients)), coefficients);
+    }
+
+    #[test]
+    fn test_coset_fft() {
+        let domain = Radix2Domain::<Fr>::new_coset(8, Fr::from(7)).unwrap();
+        assert!(domain.is_coset());
+        assert_eq!(
+            domain.element(3),
+            Fr::from(7) * domain.generator.pow([3u64])
+        );
+        let coefficients: Vec<Fr> = (0..8).map(|i| Fr::from(2 * i + 1)).collect();
+        let evaluations = domain.fft(&coefficients);
+        assert_eq!(evaluations, naive_evaluations(&coefficients, &domain));
+        assert_eq!(domain.ifft(&evaluations), coefficients);
+    }
+
+    #[test]
+    fn test_vanishing_polynomial() {
+        let domain = Radix2Domain::<Fr>::new_coset(4, Fr::from(3)).unwrap();
+        let vanishing_poly = domain.vanishing_polynomial();
+        assert_eq!(vanishing_poly.degree, 4);
+        let sparse_vanishing_poly = domain.sparse_vanishing_polynomial();
+        assert_eq!(sparse_vanishing_poly.to_dense(), vanishing_poly);
+        for x in domain.elements() {
+            assert_eq!(vanishing_poly.evaluate(x), Fr::from(0));
+            assert_eq!(sparse_vanishing_poly.evaluate(x), Fr::from(0));
+            assert_eq!(domain.evaluate_vanishing_polynomial(x), Fr::from(0));
+        }
+        assert_eq!(
+            domain.evaluate_vanishing_polynomial(Fr::from(2)),
+            Fr::from(16) - Fr::from(81)
+        );
+    }
+}
@@ -0,0 +1,384 @@
+use crate::dense_polynomial::UnivariatePolynomialDense;
+use crate::domain::Radix2Domain;
+use crate::ops::forward_binary_op;
+use ark_ff::{batch_inversion, PrimeField};
+use std::ops::{Add, Mul, Neg, Sub};
+use std::sync::Arc;
+
+// ==============================================================//
+//    @note Lagrange domain
+// =============================================================//
+
+/// A set of distinct interpolation points x_0, ..., x_(n-1) together with their barycentric
+/// weights w_j = 1 / prod_(k != j) (x_j - x_k).
+///
+/// The weights only depend on the points, so they are computed once and shared by every
+/// polynomial over the domain.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct LagrangeDomain<F: PrimeField> {
+    pub points: Vec<F>,
+    pub weights: Vec<F>,
+    // set when the points are the elements of a radix-2 subgroup (or coset), in order
+    pub subgroup: Option<Radix2Domain<F>>,
+}
+
+impl<F: PrimeField> LagrangeDomain<F> {
+    /// Creates a domain over arbitrary distinct points, computing the weights in O(n^2).
+    pub fn new(points: Vec<F>) -> Self {
+        if points.is_empty() {
+            panic!("domain cannot be empty!");
+        }
+        let mut weights = vec![F::one(); points.len()];
+        for (j, x_j) in points.iter().enumerate() {
+            for (k, x_k) in points.iter().enumerate() {
+                if j != k {
+                    weights[j] *= *x_j - x_k;
+                }
+            }
+        }
+        if weights.iter().any(|weight| weight.is_zero()) {
+            panic!("domain points must be distinct");
+        }
+        batch_inversion(&mut weights);
+        Self {
+            points,
+            weights,
+            subgroup: None,
+        }
+    }
+
+    /// Creates a domain over the elements of a radix-2 subgroup (or coset) in O(n).
+    ///
+    /// With Z(x) = x^n - h^n, the weights are w_j = 1 / Z'(x_j) = x_j / (n * h^n).
+    pub fn from_subgroup(domain: Radix2Domain<F>) -> Self {
+        let points = domain.elements();
+        let scale = (F::from(domain.size as u64) * domain.offset_pow_size)
+            .inverse()
+            .unwrap();
+        let weights = points.iter().map(|x_j| *x_j * scale).collect();
+        Self {
+            points,
+            weights,
+            subgroup: Some(domain),
+        }
+    }
+
+    pub fn size(&self) -> usize {
+        self.points.len()
+    }
+
+    /// Returns [L_0(x), ..., L_(n-1)(x)], the Lagrange basis polynomials evaluated at `x`, in O(n).
+    pub fn lagrange_coefficients(&self, x: F) -> Vec<F> {
+        let mut differences: Vec<F> = self.points.iter().map(|x_j| x - x_j).collect();
+        if let Some(j) = differences
+            .iter()
+            .position(|difference| difference.is_zero())
+        {
+            // x is the j-th point, so L_j(x) = 1 and every other basis polynomial is 0
+            let mut coefficients = vec![F::zero(); self.size()];
+            coefficients[j] = F::one();
+            return coefficients;
+        }
+        batch_inversion(&mut differences);
+        // second barycentric form: L_j(x) = (w_j / (x - x_j)) / sum_k (w_k / (x - x_k))
+        let mut coefficients: Vec<F> = differences
+            .iter()
+            .zip(&self.weights)
+            .map(|(inverse, weight)| *inverse * weight)
+            .collect();
+        let denominator_inverse = coefficients.iter().sum::<F>().inverse().unwrap();
+        for coefficient in coefficients.iter_mut() {
+            *coefficient *= denominator_inverse;
+        }
+        coefficients
+    }
+
+    // returns prod_j (x - x_j), the polynomial vanishing on every point of the domain
+    pub fn vanishing_polynomial(&self) -> UnivariatePolynomialDense<F> {
+        if let Some(subgroup) = &self.subgroup {
+            return subgroup.vanishing_polynomial();
+        }
+        self.points.iter().fold(
+            UnivariatePolynomialDense::new(vec![F::one()]),
+            |acc, x_j| {
+                acc.polynomial_multiplication(&UnivariatePolynomialDense::new(vec![
+                    -*x_j,
+                    F::one(),
+                ]))
+            },
+        )
+    }
+}
+
+// ==============================================================//
+//    @note Univariate polynomial in evaluation (Lagrange) form
+// =============================================================//
+
+/// A univariate polynomial of degree < n represented by its evaluations over an n-point
+/// `LagrangeDomain`: f(x) = sum_j evaluations[j] * L_j(x).
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct UnivariateEvalForm<F: PrimeField> {
+    pub domain: Arc<LagrangeDomain<F>>,
+    pub evaluations: Vec<F>,
+}
+
+type EvalForm<F> = UnivariateEvalForm<F>;
+
+impl<F: PrimeField> UnivariateEvalForm<F> {
+    // evaluations[j] is the value of the polynomial at domain.points[j]
+    pub fn new(domain: Arc<LagrangeDomain<F>>, evaluations: Vec<F>) -> Self {
+        if evaluations.len() != domain.size() {
+            panic!(
+                "expected {} evaluations, got {}",
+                domain.size(),
+                evaluations.len()
+            );
+        }
+        Self {
+            domain,
+            evaluations,
+        }
+    }
+
+    /// Evaluates the polynomial at any `x` in O(n) using the second barycentric formula
+    /// f(x) = (sum_j w_j y_j / (x - x_j)) / (sum_j w_j / (x - x_j)).
+    pub fn evaluate(&self, x: F) -> F {
+        self.domain
+            .lagrange_coefficients(x)
+            .iter()
+            .zip(&self.evaluations)
+            .map(|(basis, y)| *basis * y)
+            .sum()
+    }
+
+    /// Converts to coefficient form: O(n log n) over a subgroup, O(n^2) over arbitrary points.
+    pub fn to_dense(&self) -> UnivariatePolynomialDense<F> {
+        if let Some(subgroup) = &self.domain.subgroup {
+            return UnivariatePolynomialDense::interpolate_over_domain(subgroup, &self.evaluations);
+        }
+        // f(x) = sum_j y_j * w_j * M(x) / (x - x_j) where M(x) = prod_k (x - x_k)
+        let vanishing_poly = self.domain.vanishing_polynomial();
+        let mut coefficients = vec![F::zero(); self.domain.size()];
+        for ((x_j, w_j), y_j) in self
+            .domain
+            .points
+            .iter()
+            .zip(&self.domain.weights)
+            .zip(&self.evaluations)
+        {
+            if y_j.is_zero() {
+                continue;
+            }
+            let (basis_numerator, _) = vanishing_poly.divide_by_linear(*x_j);
+            let scale = *w_j * y_j;
+            for (coefficient, value) in coefficients.iter_mut().zip(&basis_numerator.coefficients) {
+                *coefficient += scale * value;
+            }
+        }
+        UnivariatePolynomialDense::new(coefficients)
+    }
+
+    // evaluates a coefficient form polynomial of degree < n over the domain
+    pub fn from_dense(
+        polynomial: &UnivariatePolynomialDense<F>,
+        domain: Arc<LagrangeDomain<F>>,
+    ) -> Self {
+        if polynomial.coefficients.len() > domain.size() {
+            panic!(
+                "a polynomial of degree {} cannot be represented over {} points",
+                polynomial.degree,
+                domain.size()
+            );
+        }
+        let evaluations = match &domain.subgroup {
+            Some(subgroup) => polynomial.evaluate_over_domain(subgroup),
+            None => domain
+                .points
+                .iter()
+                .map(|x| polynomial.evaluate(*x))
+                .collect(),
+        };
+        Self::new(domain, evaluations)
+    }
+
+    pub fn scalar_multiplication(&self, scalar: F) -> Self {
+        Self::new(
+            self.domain.clone(),
+            self.evaluations.iter().map(|y| *y * scalar).collect(),
+        )
+    }
+
+    // applies `op` to every pair of evaluations
+    fn pointwise(&self, other: &UnivariateEvalForm<F>, op: impl Fn(F, F) -> F) -> Self {
+        if !Arc::ptr_eq(&self.domain, &other.domain) && self.domain != other.domain {
+            panic!("polynomials must be over the same domain");
+        }
+        let evaluations = self
+            .evaluations
+            .iter()
+            .zip(&other.evaluations)
+            .map(|(a, b)| op(*a, *b))
+            .collect();
+        Self::new(self.domain.clone(), evaluations)
+    }
+
+    pub fn pointwise_addition(&self, other: &UnivariateEvalForm<F>) -> Self {
+        self.pointwise(other, |a, b| a + b)
+    }
+
+    pub fn pointwise_subtraction(&self, other: &UnivariateEvalForm<F>) -> Self {
+        self.pointwise(other, |a, b| a - b)
+    }
+
+    /// Multiplies evaluations pointwise. The result only represents the true product when
+    /// deg(self) + deg(other) < n, otherwise it is the product reduced modulo the vanishing
+    /// polynomial of the domain.
+    pub fn pointwise_multiplication(&self, other: &UnivariateEvalForm<F>) -> Self {
+        self.pointwise(other, |a, b| a * b)
+    }
+}
+
+forward_binary_op!(
+    Add,
+    add,
+    EvalForm,
+    EvalForm,
+    EvalForm,
+    EvalForm::pointwise_addition
+);
+forward_binary_op!(
+    Sub,
+    sub,
+    EvalForm,
+    EvalForm,
+    EvalForm,
+    EvalForm::pointwise_subtraction
+);
+forward_binary_op!(
+    Mul,
+    mul,
+    EvalForm,
+    EvalForm,
+    EvalForm,
+    EvalForm::pointwise_multiplication
+);
+
+impl<F: PrimeField> Neg for &UnivariateEvalForm<F> {
+    type Output = UnivariateEvalForm<F>;
+
+    fn neg(self) -> Self::Output {
+        self.scalar_multiplication(-F::one())
+    }
+}
+
+impl<F: PrimeField> Neg for UnivariateEvalForm<F> {
+    type Output = UnivariateEvalForm<F>;
+
+    fn neg(self) -> Self::Output {
+        -&self
+    }
+}
+
+impl<F: PrimeField> Mul<F> for &UnivariateEvalForm<F> {
+    type Output = UnivariateEvalForm<F>;
+
+    fn mul(self, scalar: F) -> Self::Output {
+        self.scalar_multiplication(scalar)
+    }
+}
+
+impl<F: PrimeField> Mul<F> for UnivariateEvalForm<F> {
+    type Output = UnivariateEvalForm<F>;
+
+    fn mul(self, scalar: F) -> Self::Output {
+        self.scalar_multiplication(scalar)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use ark_bn254::{Fq, Fr};
+    use ark_ff::Field;
+
+    fn to_fq(values: &[i64]) -> Vec<Fq> {
+        values.iter().map(|value| Fq::from(*value)).collect()
+    }
+
+    fn arbitrary_domain() -> Arc<LagrangeDomain<Fq>> {
+        Arc::new(LagrangeDomain::new(to_fq(&[0, 1, 2, 5])))
+    }
+
+    #[test]
+    fn test_weights() {
+        let domain = LagrangeDomain::new(to_fq(&[0, 1, 2]));
+        // w_0 = 1 / ((0 - 1)(0 - 2)), w_1 = 1 / ((1 - 0)(1 - 2)), w_2 = 1 / ((2 - 0)(2 - 1))
+        let expected: Vec<Fq> = to_fq(&[2, -1, 2])
+            .iter()
+            .map(|d| d.inverse().unwrap())
+            .collect();
+        assert_eq!(domain.weights, expected);
+    }
+
+    #[test]
+    #[should_panic]
+    fn test_duplicate_points() {
+        LagrangeDomain::new(to_fq(&[1, 2, 1]));
+    }
+
+    #[test]
+    fn test_barycentric_evaluation() {
+        // f(x) = 2x^2 + 2
+        let poly = UnivariatePolynomialDense::new(to_fq(&[2, 0, 2]));
+        let eval_form = UnivariateEvalForm::from_dense(&poly, arbitrary_domain());
+        assert_eq!(eval_form.evaluations, to_fq(&[2, 4, 10, 52]));
+        for x in [3, 7, 100, 5] {
+            assert_eq!(eval_form.evaluate(Fq::from(x)), poly.evaluate(Fq::from(x)));
+        }
+    }
+
+    #[test]
+    fn test_to_dense_arbitrary_domain() {
+        let poly = UnivariatePolynomialDense::new(to_fq(&[3, -1, 4, 1]));
+        let eval_form = UnivariateEvalForm::from_dense(&poly, arbitrary_domain());
+        assert_eq!(eval_form.to_dense(), poly);
+    }
+
+    #[test]
+    fn test_subgroup_domain() {
+        let subgroup = Radix2Domain::<Fr>::new_coset(8, Fr::from(5)).unwrap();
+        let domain = Arc::new(LagrangeDomain::from_subgroup(subgroup));
+        let slow_domain = LagrangeDomain::new(subgroup.elements());
+        assert_eq!(domain.weights, slow_domain.weights);
+
+        let poly = UnivariatePolynomialDense::new((1..=8).map(|i| Fr::from(i * i)).collect());
+        let eval_form = UnivariateEvalForm::from_dense(&poly, domain);
+        assert_eq!(eval_form.evaluate(Fr::from(3)), poly.evaluate(Fr::from(3)));
+        assert_eq!(eval_form.to_dense(), poly);
+    }
+
+    #[test]
+    fn test_pointwise_ops() {
+        let domain = arbitrary_domain();
+        let p = UnivariatePolynomialDense::new(to_fq(&[1, 2]));
+        let q = UnivariatePolynomialDense::new(to_fq(&[3, 0, 1]));
+        let p_evals = UnivariateEvalForm::from_dense(&p, domain.clone());
+        let q_evals = UnivariateEvalForm::from_dense(&q, domain);
+
+        assert_eq!((&p_evals + &q_evals).to_dense(), &p + &q);
+        assert_eq!((&p_evals - &q_evals).to_dense(), &p - &q);
+        // deg(p) + deg(q) = 3 < 4 points, so the pointwise product is exact
+        assert_eq!((&p_evals * &q_evals).to_dense(), &p * &q);
+        assert_eq!((&p_evals * Fq::from(3)).to_dense(), &p * Fq::from(3));
+        assert_eq!((-p_evals).to_dense(), -p);
+    }
+
+    #[test]
+    #[should_panic]
+    fn test_pointwise_ops_different_domains() {
+        let p = UnivariateEvalForm::new(arbitrary_domain(), to_fq(&[1, 2, 3, 4]));
+        let other_domain = Arc::new(LagrangeDomain::new(to_fq(&[1, 2, 3, 4])));
+        let q = UnivariateEvalForm::new(other_domain, to_fq(&[1, 2, 3, 4]));
+        let _ = p + q;
+    }
+}
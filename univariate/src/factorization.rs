@@ -0,0 +1,298 @@
+use crate::dense_polynomial::UnivariatePolynomialDense;
+use ark_ff::{BitIteratorBE, One, PrimeField};
+
+// ==============================================================//
+//    @note factorization over F_p (Cantor–Zassenhaus), p is assumed odd
+// =============================================================//
+
+// a random try splits a valid input with probability at least 1/2, so all of them fail on a valid
+// input with probability at most 2^-64
+pub const EQUAL_DEGREE_ATTEMPTS: usize = 64;
+
+impl<F: PrimeField> UnivariatePolynomialDense<F> {
+    /// Computes self^exponent mod `modulus` by square-and-multiply, the exponent is given as
+    /// little-endian u64 limbs so it can exceed the field size (e.g. F::MODULUS).
+    pub fn pow_mod(
+        &self,
+        exponent: &[u64],
+        modulus: &UnivariatePolynomialDense<F>,
+    ) -> UnivariatePolynomialDense<F> {
+        let base = self.rem(modulus).unwrap();
+        let mut result = UnivariatePolynomialDense::one().rem(modulus).unwrap();
+        for bit in BitIteratorBE::without_leading_zeros(exponent) {
+            result = (&result * &result).rem(modulus).unwrap();
+            if bit {
+                result = (&result * &base).rem(modulus).unwrap();
+            }
+        }
+        result
+    }
+
+    /// Yun's square-free decomposition: returns `(a_i, i)` such that self = lc * prod a_i^i,
+    /// where every a_i is monic, square-free and pairwise coprime.
+    ///
+    /// Valid as long as deg(self) < p, which always holds for the fields used here.
+    pub fn square_free_factorization(&self) -> Vec<(UnivariatePolynomialDense<F>, usize)> {
+        if self.is_zero() {
+            panic!("cannot factor the zero polynomial");
+        }
+        let f = self.make_monic();
+        let mut factors = Vec::new();
+        if f.degree == 0 {
+            return factors;
+        }
+        let f_prime = f.derivative();
+        let a0 = f.gcd(&f_prime);
+        let mut b = f.divide_exact(&a0).unwrap();
+        let c = f_prime.divide_exact(&a0).unwrap();
+        let mut d = &c - &b.derivative();
+        let mut multiplicity = 1;
+        while b.degree > 0 {
+            let a = b.gcd(&d);
+            b = b.divide_exact(&a).unwrap();
+            let c = d.divide_exact(&a).unwrap();
+            d = &c - &b.derivative();
+            if a.degree > 0 {
+                factors.push((a, multiplicity));
+            }
+            multiplicity += 1;
+        }
+        factors
+    }
+
+    /// Distinct-degree factorization of a monic square-free polynomial: returns `(g_d, d)` where
+    /// g_d is the product of all irreducible factors of degree d.
+    pub fn distinct_degree_factorization(&self) -> Vec<(UnivariatePolynomialDense<F>, usize)> {
+        let mut factors = Vec::new();
+        let mut remaining = self.make_monic();
+        let x = UnivariatePolynomialDense::new(vec![F::zero(), F::one()]);
+        // h = x^(p^d) mod remaining
+        let mut h = x.clone();
+        let mut d = 1;
+        while remaining.degree as usize >= 2 * d {
+            h = h.pow_mod(F::MODULUS.as_ref(), &remaining);
+            let g = remaining.gcd(&(&h - &x));
+            if g.degree > 0 {
+                remaining = remaining.divide_exact(&g).unwrap();
+                h = h.rem(&remaining).unwrap();
+                factors.push((g, d));
+            }
+            d += 1;
+        }
+        if remaining.degree > 0 {
+            let degree = remaining.degree as usize;
+            factors.push((remaining, degree));
+        }
+        factors
+    }
+
+    /// Cantor–Zassenhaus equal-degree factorization: splits a monic square-free polynomial whose
+    /// irreducible factors all have degree `degree` into those factors. A constant has none.
+    ///
+    /// Panics if the input is not of that shape, which shows up as no split in
+    /// `EQUAL_DEGREE_ATTEMPTS` random tries.
+    // `usize::is_multiple_of` would need Rust 1.87
+    #[allow(clippy::manual_is_multiple_of)]
+    pub fn equal_degree_factorization(&self, degree: usize) -> Vec<UnivariatePolynomialDense<F>> {
+        if degree == 0 {
+            panic!("the factor degree must be positive");
+        }
+        let f = self.make_monic();
+        let n = f.degree as usize;
+        if n == 0 {
+            return Vec::new();
+        }
+        if n % degree != 0 {
+            panic!("the polynomial degree must be a multiple of the factor degree");
+        }
+        if n == degree {
+            return vec![f];
+        }
+        let mut rng = rand::thread_rng();
+        for _ in 0..EQUAL_DEGREE_ATTEMPTS {
+            let a = UnivariatePolynomialDense::new((0..n).map(|_| F::rand(&mut rng)).collect());
+            if a.degree == 0 {
+                continue;
+            }
+            // a^((p^d - 1) / 2) = (a * a^p * ... * a^(p^(d-1)))^((p - 1) / 2)
+            let mut frobenius = a.clone();
+            let mut norm = a.clone();
+            for _ in 1..degree {
+                frobenius = frobenius.pow_mod(F::MODULUS.as_ref(), &f);
+                norm = (&norm * &frobenius).rem(&f).unwrap();
+            }
+            let b = norm.pow_mod(F::MODULUS_MINUS_ONE_DIV_TWO.as_ref(), &f);
+            let g = f.gcd(&(&b - &UnivariatePolynomialDense::one()));
+            if g.degree > 0 && (g.degree as usize) < n {
+                let mut factors = g.equal_degree_factorization(degree);
+                factors.extend(
+                    f.divide_exact(&g)
+                        .unwrap()
+                        .equal_degree_factorization(degree),
+                );
+                return factors;
+            }
+        }
+        panic!(
+            "the polynomial is not square-free with all factors of degree {}",
+            degree
+        );
+    }
+
+    /// Factors the polynomial into monic irreducible factors with multiplicities, sorted by
+    /// degree and then coefficients. The leading coefficient is dropped.
+    pub fn factor(&self) -> Vec<(UnivariatePolynomialDense<F>, usize)> {
+        let mut factors = Vec::new();
+        for (square_free, multiplicity) in self.square_free_factorization() {
+            for (product, degree) in square_free.distinct_degree_factorization() {
+                for factor in product.equal_degree_factorization(degree) {
+                    factors.push((factor, multiplicity));
+                }
+            }
+        }
+        factors.sort_by(|(a, _), (b, _)| {
+            a.degree
+                .cmp(&b.degree)
+                .then_with(|| a.coefficients.cmp(&b.coefficients))
+        });
+        factors
+    }
+
+    /// Returns every root in F together with its multiplicity, sorted by root. Panics on the zero
+    /// polynomial, which vanishes everywhere.
+    pub fn roots(&self) -> Vec<(F, usize)> {
+        let x = UnivariatePolynomialDense::new(vec![F::zero(), F::one()]);
+        let mut roots = Vec::new();
+        for (square_free, multiplicity) in self.square_free_factorization() {
+            // gcd with x^p - x keeps exactly the linear factors
+            let x_pow_p = x.pow_mod(F::MODULUS.as_ref(), &square_free);
+            let linear_part = square_free.gcd(&(&x_pow_p - &x));
+            if linear_part.degree == 0 {
+                continue;
+            }
+            for factor in linear_part.equal_degree_factorization(1) {
+                roots.push((-factor.coefficients[0], multiplicity));
+            }
+        }
+        roots.sort();
+        roots
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use ark_bn254::{Fq, Fr};
+
+    fn poly(values: &[i64]) -> UnivariatePolynomialDense<Fq> {
+        UnivariatePolynomialDense::new(values.iter().map(|value| Fq::from(*value)).collect())
+    }
+
+    fn from_roots<F: PrimeField>(roots: &[(F, usize)]) -> UnivariatePolynomialDense<F> {
+        let mut result = UnivariatePolynomialDense::one();
+        for (root, multiplicity) in roots {
+            for _ in 0..*multiplicity {
+                result = &result * &UnivariatePolynomialDense::new(vec![-*root, F::one()]);
+            }
+        }
+        result
+    }
+
+    #[test]
+    fn test_pow_mod() {
+        let modulus = poly(&[1, 0, 1]);
+        let x = poly(&[0, 1]);
+        // x^2 = -1 mod x^2 + 1, so x^5 = x
+        assert_eq!(x.pow_mod(&[5], &modulus), x);
+        assert_eq!(x.pow_mod(&[0], &modulus), poly(&[1]));
+        // x^p = x for the field itself, and (x - 3)^p = x^p - 3
+        let linear = poly(&[-3, 1]);
+        let modulus = poly(&[7, 2, 0, 5, 1]);
+        assert_eq!(
+            linear.pow_mod(Fq::MODULUS.as_ref(), &modulus),
+            &x.pow_mod(Fq::MODULUS.as_ref(), &modulus) - &poly(&[3])
+        );
+    }
+
+    #[test]
+    fn test_square_free_factorization() {
+        let a = poly(&[-1, 1]);
+        let b = poly(&[2, 1]);
+        let c = poly(&[1, 0, 1]);
+        let f = &(&a * &(&b * &b)) * &(&(&c * &c) * &(&c * &poly(&[3])));
+        let factors = f.square_free_factorization();
+        assert_eq!(factors, vec![(a, 1), (b, 2), (c, 3)]);
+    }
+
+    #[test]
+    fn test_roots() {
+        let expected = vec![
+            (Fq::from(0), 1),
+            (Fq::from(3), 2),
+            (Fq::from(17), 1),
+            (-Fq::from(5), 3),
+        ];
+        let mut sorted = expected.clone();
+        sorted.sort();
+        // no roots are added by an irreducible quadratic factor or a constant
+        let f = &from_roots(&expected) * &poly(&[7, 0, 7]);
+        assert_eq!(f.roots(), sorted);
+        assert!(poly(&[1, 0, 1]).roots().is_empty());
+        assert!(poly(&[5]).roots().is_empty());
+    }
+
+    #[test]
+    fn test_roots_over_fr() {
+        let expected: Vec<(Fr, usize)> = (1..20).map(|i| (Fr::from(i * i * 1000 + 7), 1)).collect();
+        let mut sorted = expected.clone();
+        sorted.sort();
+        assert_eq!(from_roots(&expected).roots(), sorted);
+    }
+
+    #[test]
+    fn test_factor() {
+        // x^2 + 1 is irreducible since p = 3 mod 4
+        let quadratic = poly(&[1, 0, 1]);
+        let linear = poly(&[4, 1]);
+        let f = &(&quadratic * &quadratic) * &(&linear * &poly(&[2]));
+        assert_eq!(
+            f.factor(),
+            vec![(linear.clone(), 1), (quadratic.clone(), 2)]
+        );
+
+        let cubic = &(&linear * &poly(&[-9, 1])) * &poly(&[11, 1]);
+        let factors = (&cubic * &quadratic).factor();
+        assert_eq!(factors.len(), 4);
+        let product = factors
+            .iter()
+            .fold(UnivariatePolynomialDense::one(), |acc, (factor, _)| {
+                &acc * factor
+            });
+        assert_eq!(product, &cubic * &quadratic);
+        assert!(factors.iter().all(|(factor, _)| factor.degree <= 2));
+    }
+
+    #[test]
+    fn test_distinct_degree_factorization() {
+        let linear = &poly(&[1, 1]) * &poly(&[-6, 1]);
+        let quadratic = poly(&[1, 0, 1]);
+        let f = &linear * &quadratic;
+        assert_eq!(
+            f.distinct_degree_factorization(),
+            vec![(linear, 1), (quadratic, 2)]
+        );
+    }
+
+    #[test]
+    fn test_equal_degree_factorization_of_a_constant() {
+        assert!(poly(&[5]).equal_degree_factorization(1).is_empty());
+        assert!(poly(&[5]).equal_degree_factorization(2).is_empty());
+    }
+
+    #[test]
+    #[should_panic]
+    fn test_equal_degree_factorization_wrong_degree() {
+        // x^2 + 1 has no linear factors, so no try can split it into them
+        poly(&[1, 0, 1]).equal_degree_factorization(1);
+    }
+}
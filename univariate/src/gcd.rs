@@ -0,0 +1,241 @@
+use crate::dense_polynomial::UnivariatePolynomialDense;
+use crate::multipoint::SubproductTree;
+use ark_ff::{One, PrimeField, Zero};
+
+impl<F: PrimeField> UnivariatePolynomialDense<F> {
+    // scales the polynomial so its leading coefficient is one, the zero polynomial stays zero
+    pub fn make_monic(&self) -> UnivariatePolynomialDense<F> {
+        if self.is_zero() {
+            return self.clone();
+        }
+        self.scalar_multiplication(self.leading_coefficient().inverse().unwrap())
+    }
+
+    /// Monic greatest common divisor of `self` and `other`, gcd(0, 0) is the zero polynomial.
+    pub fn gcd(&self, other: &UnivariatePolynomialDense<F>) -> UnivariatePolynomialDense<F> {
+        let mut a = self.clone();
+        let mut b = other.clone();
+        while !b.is_zero() {
+            let remainder = a.rem(&b).unwrap();
+            a = b;
+            b = remainder;
+        }
+        a.make_monic()
+    }
+
+    /// Extended Euclidean algorithm: returns `(g, s, t)` with `s * self + t * other = g`,
+    /// where `g` is the monic gcd.
+    pub fn xgcd(
+        &self,
+        other: &UnivariatePolynomialDense<F>,
+    ) -> (
+        UnivariatePolynomialDense<F>,
+        UnivariatePolynomialDense<F>,
+        UnivariatePolynomialDense<F>,
+    ) {
+        let (mut r0, mut r1) = (self.clone(), other.clone());
+        let (mut s0, mut s1) = (
+            UnivariatePolynomialDense::one(),
+            UnivariatePolynomialDense::zero(),
+        );
+        let (mut t0, mut t1) = (
+            UnivariatePolynomialDense::zero(),
+            UnivariatePolynomialDense::one(),
+        );
+        while !r1.is_zero() {
+            let (quotient, remainder) = r0.div_rem(&r1).unwrap();
+            let s2 = &s0 - &quotient * &s1;
+            let t2 = &t0 - &quotient * &t1;
+            (r0, r1) = (r1, remainder);
+            (s0, s1) = (s1, s2);
+            (t0, t1) = (t1, t2);
+        }
+        normalize(r0, s0, t0)
+    }
+
+    /// Runs the extended Euclidean algorithm only until the first remainder of degree
+    /// below `degree_bound`, returning `(r, s, t)` with `s * self + t * other = r`.
+    ///
+    /// This is the half-way point used by Gao decoding and rational reconstruction. The result is
+    /// scaled so that `r` is monic (unless it is zero).
+    pub fn partial_xgcd(
+        &self,
+        other: &UnivariatePolynomialDense<F>,
+        degree_bound: usize,
+    ) -> (
+        UnivariatePolynomialDense<F>,
+        UnivariatePolynomialDense<F>,
+        UnivariatePolynomialDense<F>,
+    ) {
+        let below_bound =
+            |r: &UnivariatePolynomialDense<F>| r.is_zero() || (r.degree as usize) < degree_bound;
+
+        let (mut r0, mut r1) = (self.clone(), other.clone());
+        let (mut s0, mut s1) = (
+            UnivariatePolynomialDense::one(),
+            UnivariatePolynomialDense::zero(),
+        );
+        let (mut t0, mut t1) = (
+            UnivariatePolynomialDense::zero(),
+            UnivariatePolynomialDense::one(),
+        );
+        if below_bound(&r0) {
+            return normalize(r0, s0, t0);
+        }
+        while !below_bound(&r1) {
+            let (quotient, remainder) = r0.div_rem(&r1).unwrap();
+            let s2 = &s0 - &quotient * &s1;
+            let t2 = &t0 - &quotient * &t1;
+            (r0, r1) = (r1, remainder);
+            (s0, s1) = (s1, s2);
+            (t0, t1) = (t1, t2);
+        }
+        normalize(r1, s1, t1)
+    }
+
+    /// Finds a rational function N(x) / D(x) with deg(N) <= `numerator_degree` and
+    /// deg(N) + deg(D) < n that takes `y_values[i]` at `x_values[i]`.
+    ///
+    /// Returns `(numerator, denominator)` with a monic denominator, or `None` if no such function
+    /// exists (some D(x_i) would vanish).
+    pub fn reconstruct_rational_function(
+        x_values: &[F],
+        y_values: &[F],
+        numerator_degree: usize,
+    ) -> Option<(UnivariatePolynomialDense<F>, UnivariatePolynomialDense<F>)> {
+        if x_values.len() != y_values.len() {
+            panic!("The number of x values must be equal to the number of y values");
+        }
+        if numerator_degree >= x_values.len() {
+            panic!("the numerator degree must be smaller than the number of points");
+        }
+        let interpolant = UnivariatePolynomialDense::interpolate_fast(x_values, y_values);
+        let tree = SubproductTree::new(x_values);
+
+        let (numerator, _, denominator) =
+            tree.root().partial_xgcd(&interpolant, numerator_degree + 1);
+        if denominator.is_zero() || x_values.iter().any(|x| denominator.evaluate(*x).is_zero()) {
+            return None;
+        }
+        let scale = denominator.leading_coefficient().inverse().unwrap();
+        Some((
+            numerator.scalar_multiplication(scale),
+            denominator.scalar_multiplication(scale),
+        ))
+    }
+
+    // a polynomial is square-free iff it shares no factor with its derivative
+    pub fn is_square_free(&self) -> bool {
+        if self.is_zero() {
+            return false;
+        }
+        self.gcd(&self.derivative()).degree == 0
+    }
+}
+
+// divides (r, s, t) by the leading coefficient of r so the remainder is monic
+fn normalize<F: PrimeField>(
+    r: UnivariatePolynomialDense<F>,
+    s: UnivariatePolynomialDense<F>,
+    t: UnivariatePolynomialDense<F>,
+) -> (
+    UnivariatePolynomialDense<F>,
+    UnivariatePolynomialDense<F>,
+    UnivariatePolynomialDense<F>,
+) {
+    if r.is_zero() {
+        return (r, s, t);
+    }
+    let scale = r.leading_coefficient().inverse().unwrap();
+    (
+        r.scalar_multiplication(scale),
+        s.scalar_multiplication(scale),
+        t.scalar_multiplication(scale),
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use ark_bn254::Fq;
+    use ark_ff::Field;
+
+    fn poly(values: &[i64]) -> UnivariatePolynomialDense<Fq> {
+        UnivariatePolynomialDense::new(values.iter().map(|value| Fq::from(*value)).collect())
+    }
+
+    #[test]
+    fn test_gcd() {
+        // (x - 1)(x - 2) and 3(x - 1)(x + 5)
+        let common = poly(&[-1, 1]);
+        let a = &common * &poly(&[-2, 1]);
+        let b = &common * &poly(&[15, 3]);
+        assert_eq!(a.gcd(&b), common);
+        assert_eq!(b.gcd(&a), common);
+        assert_eq!(poly(&[-2, 1]).gcd(&poly(&[5, 1])), poly(&[1]));
+        assert_eq!(a.gcd(&UnivariatePolynomialDense::zero()), a);
+        assert!(UnivariatePolynomialDense::<Fq>::zero()
+            .gcd(&UnivariatePolynomialDense::zero())
+            .is_zero());
+    }
+
+    #[test]
+    fn test_xgcd_bezout_identity() {
+        let a = poly(&[4, 0, -3, 2, 7]);
+        let b = poly(&[-6, 1, 0, 5]);
+        let (g, s, t) = a.xgcd(&b);
+        assert_eq!(&s * &a + &t * &b, g);
+        assert_eq!(g, a.gcd(&b));
+
+        let common = poly(&[3, 2, 1]);
+        let (g, s, t) = (&a * &common).xgcd(&(&b * &common));
+        assert_eq!(g, common.make_monic());
+        assert_eq!(&s * &a * &common + &t * &b * &common, g);
+    }
+
+    #[test]
+    fn test_partial_xgcd() {
+        let a = poly(&[1, 2, 3, 4, 5, 6, 7, 1]);
+        let b = poly(&[9, -1, 0, 2, 8, 1]);
+        for bound in 0..8 {
+            let (r, s, t) = a.partial_xgcd(&b, bound);
+            assert!(r.is_zero() || (r.degree as usize) < bound);
+            assert_eq!(&s * &a + &t * &b, r);
+        }
+    }
+
+    #[test]
+    fn test_reconstruct_rational_function() {
+        // (x + 3) / (x^2 + 1) sampled away from the poles
+        let numerator = poly(&[3, 1]);
+        let denominator = poly(&[1, 0, 1]);
+        let x_values: Vec<Fq> = (2..7).map(Fq::from).collect();
+        let y_values: Vec<Fq> = x_values
+            .iter()
+            .map(|x| numerator.evaluate(*x) * denominator.evaluate(*x).inverse().unwrap())
+            .collect();
+        let (n, d) =
+            UnivariatePolynomialDense::reconstruct_rational_function(&x_values, &y_values, 1)
+                .unwrap();
+        assert_eq!(n, numerator);
+        assert_eq!(d, denominator);
+
+        // a plain polynomial comes back with denominator one
+        let (n, d) =
+            UnivariatePolynomialDense::reconstruct_rational_function(&x_values, &y_values, 4)
+                .unwrap();
+        assert_eq!(d, poly(&[1]));
+        assert_eq!(
+            n,
+            UnivariatePolynomialDense::interpolate_fast(&x_values, &y_values)
+        );
+    }
+
+    #[test]
+    fn test_is_square_free() {
+        assert!(poly(&[-2, -1, 1]).is_square_free());
+        assert!(!(&poly(&[-1, 1]) * &poly(&[-1, 1])).is_square_free());
+        assert!(poly(&[5]).is_square_free());
+        assert!(!UnivariatePolynomialDense::<Fq>::zero().is_square_free());
+    }
+}
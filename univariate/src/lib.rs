@@ -1 +1,15 @@
-pub mod dense_polynomial;
\ No newline at end of file
+pub mod composition;
+pub mod dense_polynomial;
+pub mod division;
+pub mod domain;
+pub mod evaluation_form;
+pub mod factorization;
+pub mod gcd;
+pub mod multipoint;
+pub mod newton;
+pub mod ops;
+mod parallel;
+pub mod parse;
+pub mod reed_solomon;
+pub mod serialization;
+pub mod sparse_polynomial;
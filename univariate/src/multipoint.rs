@@ -0,0 +1,207 @@
+use crate::dense_polynomial::UnivariatePolynomialDense;
+use crate::parallel::{cfg_chunks, cfg_iter};
+use ark_ff::{batch_inversion, PrimeField, Zero};
+#[cfg(feature = "parallel")]
+use rayon::prelude::*;
+
+// below this many points, evaluating with Horner's method point by point is faster than
+// building a subproduct tree
+pub const MULTIPOINT_THRESHOLD: usize = 32;
+
+/// A subproduct tree over the points x_0, ..., x_(n-1).
+///
+/// The leaves are the linear factors (x - x_i) and every node is the product of its two children,
+/// so the root is M(x) = prod_i (x - x_i). When a layer has an odd number of nodes the last one
+/// is carried up unchanged.
+pub struct SubproductTree<F: PrimeField> {
+    pub points: Vec<F>,
+    // layers[0] holds the leaves, the last layer holds the root
+    pub layers: Vec<Vec<UnivariatePolynomialDense<F>>>,
+}
+
+impl<F: PrimeField> SubproductTree<F> {
+    pub fn new(points: &[F]) -> Self {
+        if points.is_empty() {
+            panic!("cannot build a subproduct tree over zero points");
+        }
+        let leaves: Vec<UnivariatePolynomialDense<F>> = cfg_iter!(points)
+            .map(|x| UnivariatePolynomialDense::new(vec![-*x, F::one()]))
+            .collect();
+        let mut layers = vec![leaves];
+        while layers.last().unwrap().len() > 1 {
+            let next_layer = cfg_chunks!(layers.last().unwrap(), 2)
+                .map(|pair| match pair {
+                    [left, right] => left.polynomial_multiplication(right),
+                    [carried] => carried.clone(),
+                    _ => unreachable!(),
+                })
+                .collect();
+            layers.push(next_layer);
+        }
+        Self {
+            points: points.to_vec(),
+            layers,
+        }
+    }
+
+    // returns M(x) = prod_i (x - x_i)
+    pub fn root(&self) -> &UnivariatePolynomialDense<F> {
+        &self.layers.last().unwrap()[0]
+    }
+
+    /// Evaluates `polynomial` at every point by pushing remainders down the tree:
+    /// f mod (x - x_i) = f(x_i).
+    pub fn evaluate(&self, polynomial: &UnivariatePolynomialDense<F>) -> Vec<F> {
+        let mut remainders = vec![polynomial.rem(self.root()).unwrap()];
+        for layer in self.layers.iter().rev().skip(1) {
+            remainders = cfg_iter!(layer)
+                .enumerate()
+                .map(|(i, node)| remainders[i / 2].rem(node).unwrap())
+                .collect();
+        }
+        remainders
+            .iter()
+            .map(|remainder| remainder.coefficients.first().copied().unwrap_or(F::zero()))
+            .collect()
+    }
+
+    /// Interpolates the unique polynomial of degree < n taking `values[i]` at x_i.
+    ///
+    /// With M'(x) the derivative of the root, the interpolant is
+    /// sum_i (values[i] / M'(x_i)) * M(x) / (x - x_i), which is combined bottom up the tree.
+    pub fn interpolate(&self, values: &[F]) -> UnivariatePolynomialDense<F> {
+        if values.len() != self.points.len() {
+            panic!("The number of x values must be equal to the number of y values");
+        }
+        let mut weights = self.evaluate(&self.root().derivative());
+        if weights.iter().any(|weight| weight.is_zero()) {
+            panic!("interpolation points must be distinct");
+        }
+        batch_inversion(&mut weights);
+
+        let mut combined: Vec<UnivariatePolynomialDense<F>> = values
+            .iter()
+            .zip(weights)
+            .map(|(value, weight)| UnivariatePolynomialDense::new(vec![*value * weight]))
+            .collect();
+        // at every node: left_combination * right_subproduct + right_combination * left_subproduct
+        for layer in self.layers.iter().take(self.layers.len() - 1) {
+            combined = cfg_chunks!(combined, 2)
+                .zip(cfg_chunks!(layer, 2))
+                .map(|(combination, nodes)| match (combination, nodes) {
+                    ([left, right], [left_node, right_node]) => left
+                        .polynomial_multiplication(right_node)
+                        .polynomial_addition(&right.polynomial_multiplication(left_node)),
+                    ([carried], _) => carried.clone(),
+                    _ => unreachable!(),
+                })
+                .collect();
+        }
+        combined.pop().unwrap()
+    }
+}
+
+impl<F: PrimeField> UnivariatePolynomialDense<F> {
+    /// Evaluates the polynomial at every point, using a subproduct tree for large point sets.
+    pub fn evaluate_many(&self, points: &[F]) -> Vec<F> {
+        if points.len() < MULTIPOINT_THRESHOLD {
+            return cfg_iter!(points).map(|x| self.evaluate(*x)).collect();
+        }
+        SubproductTree::new(points).evaluate(self)
+    }
+
+    /// Interpolates through (x_values[i], y_values[i]) with a subproduct tree, quasi-linear when
+    /// polynomial multiplication is fast.
+    pub fn interpolate_fast(x_values: &[F], y_values: &[F]) -> UnivariatePolynomialDense<F> {
+        if x_values.len() != y_values.len() {
+            panic!("The number of x values must be equal to the number of y values");
+        }
+        if x_values.is_empty() {
+            return UnivariatePolynomialDense::zero();
+        }
+        SubproductTree::new(x_values).interpolate(y_values)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use ark_bn254::{Fq, Fr};
+
+    fn to_fr(values: impl Iterator<Item = u64>) -> Vec<Fr> {
+        values.map(Fr::from).collect()
+    }
+
+    #[test]
+    fn test_subproduct_tree_root() {
+        let points = vec![Fq::from(1), Fq::from(2), Fq::from(3)];
+        let tree = SubproductTree::new(&points);
+        assert_eq!(tree.layers.len(), 3);
+        // (x - 1)(x - 2)(x - 3) = x^3 - 6x^2 + 11x - 6
+        assert_eq!(
+            tree.root().coefficients,
+            vec![-Fq::from(6), Fq::from(11), -Fq::from(6), Fq::from(1)]
+        );
+    }
+
+    #[test]
+    fn test_evaluate_many() {
+        let poly = UnivariatePolynomialDense::new(to_fr((0..150).map(|i| i * 7 + 3)));
+        let points = to_fr((0..101).map(|i| i * i + 11));
+        let expected: Vec<Fr> = points.iter().map(|x| poly.evaluate(*x)).collect();
+        assert_eq!(SubproductTree::new(&points).evaluate(&poly), expected);
+        assert_eq!(poly.evaluate_many(&points), expected);
+    }
+
+    #[test]
+    fn test_evaluate_many_low_degree() {
+        let poly = UnivariatePolynomialDense::new(vec![Fq::from(2), Fq::from(0), Fq::from(2)]);
+        let points: Vec<Fq> = (0..40).map(Fq::from).collect();
+        let tree = SubproductTree::new(&points);
+        let expected: Vec<Fq> = points.iter().map(|x| poly.evaluate(*x)).collect();
+        assert_eq!(tree.evaluate(&poly), expected);
+        assert_eq!(
+            tree.evaluate(&UnivariatePolynomialDense::zero()),
+            vec![Fq::from(0); 40]
+        );
+    }
+
+    #[test]
+    fn test_interpolate_fast() {
+        let poly = UnivariatePolynomialDense::new(to_fr((0..77).map(|i| i * i + 1)));
+        let x_values = to_fr((0..77).map(|i| 3 * i + 2));
+        let y_values = poly.evaluate_many(&x_values);
+        assert_eq!(
+            UnivariatePolynomialDense::interpolate_fast(&x_values, &y_values),
+            poly
+        );
+    }
+
+    #[test]
+    fn test_interpolate_fast_matches_lagrange() {
+        let x_values = vec![Fq::from(0), Fq::from(1), Fq::from(2)];
+        let y_values = vec![Fq::from(2), Fq::from(4), Fq::from(10)];
+        let mut expected = UnivariatePolynomialDense::zero();
+        for x in x_values.iter() {
+            let i = x_values.iter().position(|value| value == x).unwrap();
+            expected = expected.polynomial_addition(&UnivariatePolynomialDense::lagrange_basis(
+                x_values.clone(),
+                *x,
+                y_values[i],
+            ));
+        }
+        assert_eq!(
+            UnivariatePolynomialDense::interpolate_fast(&x_values, &y_values),
+            expected
+        );
+    }
+
+    #[test]
+    #[should_panic]
+    fn test_interpolate_fast_duplicate_points() {
+        UnivariatePolynomialDense::interpolate_fast(
+            &[Fq::from(1), Fq::from(2), Fq::from(1)],
+            &[Fq::from(1), Fq::from(2), Fq::from(3)],
+        );
+    }
+}
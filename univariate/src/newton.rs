@@ -0,0 +1,157 @@
+use crate::dense_polynomial::UnivariatePolynomialDense;
+use ark_ff::{batch_inversion, PrimeField};
+
+/// Interpolation in Newton form: p(x) = c_0 + c_1 (x - x_0) + c_2 (x - x_0)(x - x_1) + ...
+///
+/// Each new point costs O(n) field operations and one inversion, and leaves the earlier
+/// coefficients untouched, so point sets can grow without starting over.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct NewtonInterpolator<F: PrimeField> {
+    pub points: Vec<F>,
+    // the divided differences c_k = f[x_0, ..., x_k]
+    pub coefficients: Vec<F>,
+    // the last row of the divided-difference table, f[x_(n-1-j), ..., x_(n-1)] at index j
+    last_row: Vec<F>,
+}
+
+impl<F: PrimeField> Default for NewtonInterpolator<F> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<F: PrimeField> NewtonInterpolator<F> {
+    pub fn new() -> Self {
+        Self {
+            points: Vec::new(),
+            coefficients: Vec::new(),
+            last_row: Vec::new(),
+        }
+    }
+
+    pub fn from_points(x_values: &[F], y_values: &[F]) -> Self {
+        if x_values.len() != y_values.len() {
+            panic!("The number of x values must be equal to the number of y values");
+        }
+        let mut interpolator = Self::new();
+        for (x, y) in x_values.iter().zip(y_values) {
+            interpolator.add_point(*x, *y);
+        }
+        interpolator
+    }
+
+    pub fn len(&self) -> usize {
+        self.points.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.points.is_empty()
+    }
+
+    // adds (x, y), after which the interpolant also passes through this point
+    pub fn add_point(&mut self, x: F, y: F) {
+        if self.points.contains(&x) {
+            panic!("interpolation points must be distinct");
+        }
+        let n = self.points.len();
+        // f[x_(n-j), ..., x_n] = (f[x_(n-j+1), ..., x_n] - f[x_(n-j), ..., x_(n-1)]) / (x_n - x_(n-j))
+        // the n denominators share a single inversion
+        let mut inverses: Vec<F> = (1..=n).map(|j| x - self.points[n - j]).collect();
+        batch_inversion(&mut inverses);
+        let mut row = Vec::with_capacity(n + 1);
+        row.push(y);
+        for j in 1..=n {
+            row.push((row[j - 1] - self.last_row[j - 1]) * inverses[j - 1]);
+        }
+        self.coefficients.push(row[n]);
+        self.points.push(x);
+        self.last_row = row;
+    }
+
+    // evaluates the current interpolant with Horner's rule over the Newton basis
+    pub fn evaluate(&self, x: F) -> F {
+        let n = self.coefficients.len();
+        if n == 0 {
+            return F::zero();
+        }
+        (0..n - 1).rev().fold(self.coefficients[n - 1], |acc, k| {
+            acc * (x - self.points[k]) + self.coefficients[k]
+        })
+    }
+
+    // converts to coefficient form in O(n^2)
+    pub fn to_dense(&self) -> UnivariatePolynomialDense<F> {
+        let n = self.coefficients.len();
+        if n == 0 {
+            return UnivariatePolynomialDense::new(Vec::new());
+        }
+        let mut result = vec![self.coefficients[n - 1]];
+        for k in (0..n - 1).rev() {
+            // result = result * (x - x_k) + c_k
+            result.insert(0, F::zero());
+            for i in 0..result.len() - 1 {
+                let shifted = result[i + 1] * self.points[k];
+                result[i] -= shifted;
+            }
+            result[0] += self.coefficients[k];
+        }
+        UnivariatePolynomialDense::new(result)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use ark_bn254::Fq;
+
+    #[test]
+    fn test_newton_matches_interpolate() {
+        let x_values: Vec<Fq> = (0..8).map(|i| Fq::from(i * 3 + 1)).collect();
+        let y_values: Vec<Fq> = (0..8).map(|i| Fq::from(i * i + 5)).collect();
+        let interpolator = NewtonInterpolator::from_points(&x_values, &y_values);
+        let expected = UnivariatePolynomialDense::interpolate(x_values.clone(), y_values.clone());
+        assert_eq!(interpolator.to_dense(), expected);
+        for x in 0..20 {
+            assert_eq!(
+                interpolator.evaluate(Fq::from(x)),
+                expected.evaluate(Fq::from(x))
+            );
+        }
+    }
+
+    #[test]
+    fn test_add_point_incrementally() {
+        // points on 2x^2 + 1
+        let f = |x: u64| Fq::from(2 * x * x + 1);
+        let mut interpolator = NewtonInterpolator::new();
+        assert!(interpolator.is_empty());
+        assert_eq!(interpolator.evaluate(Fq::from(3)), Fq::from(0));
+
+        interpolator.add_point(Fq::from(0), f(0));
+        assert_eq!(interpolator.evaluate(Fq::from(3)), Fq::from(1));
+        interpolator.add_point(Fq::from(1), f(1));
+        // the line through (0, 1) and (1, 3)
+        assert_eq!(interpolator.evaluate(Fq::from(3)), Fq::from(7));
+        interpolator.add_point(Fq::from(2), f(2));
+        assert_eq!(interpolator.evaluate(Fq::from(3)), f(3));
+        let coefficients = interpolator.coefficients.clone();
+
+        // one more point on the same parabola does not raise the degree
+        interpolator.add_point(Fq::from(5), f(5));
+        assert_eq!(interpolator.len(), 4);
+        assert_eq!(interpolator.coefficients[..3], coefficients[..]);
+        assert_eq!(interpolator.coefficients[3], Fq::from(0));
+        assert_eq!(
+            interpolator.to_dense(),
+            UnivariatePolynomialDense::new(vec![Fq::from(1), Fq::from(0), Fq::from(2)])
+        );
+    }
+
+    #[test]
+    #[should_panic]
+    fn test_add_duplicate_point() {
+        let mut interpolator = NewtonInterpolator::new();
+        interpolator.add_point(Fq::from(1), Fq::from(2));
+        interpolator.add_point(Fq::from(1), Fq::from(3));
+    }
+}
@@ -0,0 +1,223 @@
+use crate::dense_polynomial::UnivariatePolynomialDense;
+use ark_ff::{One, PrimeField, Zero};
+use std::ops::{Add, AddAssign, Mul, MulAssign, Neg, Sub, SubAssign};
+
+// ==============================================================//
+//    @note operator traits for UnivariatePolynomialDense
+// =============================================================//
+
+// every binary operator is implemented once on references (&a op &b),
+// the owned combinations (a op b, a op &b, &a op b) forward to it
+macro_rules! forward_binary_op {
+    ($trait:ident, $method:ident, $lhs:ident, $rhs:ident, $output:ident, $inner:expr) => {
+        impl<'a, F: PrimeField> $trait<&'a $rhs<F>> for &$lhs<F> {
+            type Output = $output<F>;
+
+            fn $method(self, other: &'a $rhs<F>) -> Self::Output {
+                $inner(self, other)
+            }
+        }
+
+        impl<F: PrimeField> $trait<$rhs<F>> for $lhs<F> {
+            type Output = $output<F>;
+
+            fn $method(self, other: $rhs<F>) -> Self::Output {
+                $inner(&self, &other)
+            }
+        }
+
+        impl<'a, F: PrimeField> $trait<&'a $rhs<F>> for $lhs<F> {
+            type Output = $output<F>;
+
+            fn $method(self, other: &'a $rhs<F>) -> Self::Output {
+                $inner(&self, other)
+            }
+        }
+
+        impl<F: PrimeField> $trait<$rhs<F>> for &$lhs<F> {
+            type Output = $output<F>;
+
+            fn $method(self, other: $rhs<F>) -> Self::Output {
+                $inner(self, &other)
+            }
+        }
+    };
+}
+pub(crate) use forward_binary_op;
+
+type Dense<F> = UnivariatePolynomialDense<F>;
+
+forward_binary_op!(Add, add, Dense, Dense, Dense, Dense::polynomial_addition);
+forward_binary_op!(Sub, sub, Dense, Dense, Dense, Dense::polynomial_subtraction);
+forward_binary_op!(
+    Mul,
+    mul,
+    Dense,
+    Dense,
+    Dense,
+    Dense::polynomial_multiplication
+);
+
+macro_rules! forward_assign_op {
+    ($trait:ident, $method:ident, $inner:ident) => {
+        impl<'a, F: PrimeField> $trait<&'a UnivariatePolynomialDense<F>>
+            for UnivariatePolynomialDense<F>
+        {
+            fn $method(&mut self, other: &'a UnivariatePolynomialDense<F>) {
+                *self = self.$inner(other);
+            }
+        }
+
+        impl<F: PrimeField> $trait<UnivariatePolynomialDense<F>> for UnivariatePolynomialDense<F> {
+            fn $method(&mut self, other: UnivariatePolynomialDense<F>) {
+                *self = self.$inner(&other);
+            }
+        }
+    };
+}
+
+forward_assign_op!(AddAssign, add_assign, polynomial_addition);
+forward_assign_op!(SubAssign, sub_assign, polynomial_subtraction);
+forward_assign_op!(MulAssign, mul_assign, polynomial_multiplication);
+
+impl<F: PrimeField> Neg for &UnivariatePolynomialDense<F> {
+    type Output = UnivariatePolynomialDense<F>;
+
+    fn neg(self) -> Self::Output {
+        UnivariatePolynomialDense::new(self.coefficients.iter().map(|c| -*c).collect())
+    }
+}
+
+impl<F: PrimeField> Neg for UnivariatePolynomialDense<F> {
+    type Output = UnivariatePolynomialDense<F>;
+
+    fn neg(mut self) -> Self::Output {
+        for coefficient in self.coefficients.iter_mut() {
+            *coefficient = -*coefficient;
+        }
+        self
+    }
+}
+
+// scalar multiplication: p * c
+impl<F: PrimeField> Mul<F> for &UnivariatePolynomialDense<F> {
+    type Output = UnivariatePolynomialDense<F>;
+
+    fn mul(self, scalar: F) -> Self::Output {
+        self.scalar_multiplication(scalar)
+    }
+}
+
+impl<F: PrimeField> Mul<F> for UnivariatePolynomialDense<F> {
+    type Output = UnivariatePolynomialDense<F>;
+
+    fn mul(self, scalar: F) -> Self::Output {
+        self.scalar_multiplication(scalar)
+    }
+}
+
+impl<F: PrimeField> MulAssign<F> for UnivariatePolynomialDense<F> {
+    fn mul_assign(&mut self, scalar: F) {
+        *self = self.scalar_multiplication(scalar);
+    }
+}
+
+impl<F: PrimeField> Zero for UnivariatePolynomialDense<F> {
+    fn zero() -> Self {
+        UnivariatePolynomialDense::new(Vec::new())
+    }
+
+    fn is_zero(&self) -> bool {
+        self.coefficients.is_empty()
+    }
+}
+
+impl<F: PrimeField> One for UnivariatePolynomialDense<F> {
+    fn one() -> Self {
+        UnivariatePolynomialDense::new(vec![F::one()])
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use ark_bn254::Fq;
+
+    fn poly(values: &[i64]) -> UnivariatePolynomialDense<Fq> {
+        UnivariatePolynomialDense::new(values.iter().map(|value| Fq::from(*value)).collect())
+    }
+
+    #[test]
+    fn test_add_and_sub() {
+        let p = poly(&[1, 2, 3]);
+        let q = poly(&[5, 2]);
+        assert_eq!(&p + &q, poly(&[6, 4, 3]));
+        assert_eq!(p.clone() + q.clone(), poly(&[6, 4, 3]));
+        assert_eq!(&p - &q, poly(&[-4, 0, 3]));
+        assert_eq!(q - p, poly(&[4, 0, -3]));
+    }
+
+    #[test]
+    fn test_cancellation_restores_degree() {
+        let p = poly(&[1, 2, 3]);
+        let q = poly(&[4, 5, -3]);
+        let sum = &p + &q;
+        assert_eq!(sum.degree, 1);
+        assert_eq!(sum, poly(&[5, 7]));
+
+        let zero = &p + &(-&p);
+        assert!(zero.is_zero());
+        assert_eq!(zero.degree, 0);
+        assert_eq!(zero, UnivariatePolynomialDense::zero());
+        assert_eq!(&p - &p, UnivariatePolynomialDense::zero());
+    }
+
+    #[test]
+    fn test_mul() {
+        let p = poly(&[1, 2, 3]);
+        let q = poly(&[5, 2]);
+        assert_eq!(&p * &q, poly(&[5, 12, 19, 6]));
+        assert_eq!(
+            &p * UnivariatePolynomialDense::zero(),
+            UnivariatePolynomialDense::zero()
+        );
+        assert_eq!(&p * UnivariatePolynomialDense::one(), p);
+    }
+
+    #[test]
+    fn test_scalar_ops() {
+        let p = poly(&[1, 2, 3]);
+        assert_eq!(&p * Fq::from(2), poly(&[2, 4, 6]));
+        assert_eq!(&p * Fq::from(0), UnivariatePolynomialDense::zero());
+        let mut q = p.clone();
+        q *= Fq::from(3);
+        assert_eq!(q, poly(&[3, 6, 9]));
+    }
+
+    #[test]
+    fn test_assign_ops() {
+        let mut p = poly(&[1, 2, 3]);
+        p += poly(&[1, 1]);
+        assert_eq!(p, poly(&[2, 3, 3]));
+        p -= &poly(&[0, 0, 3]);
+        assert_eq!(p, poly(&[2, 3]));
+        p *= poly(&[1, 1]);
+        assert_eq!(p, poly(&[2, 5, 3]));
+    }
+
+    #[test]
+    fn test_neg() {
+        let p = poly(&[1, -2, 3]);
+        assert_eq!(-&p, poly(&[-1, 2, -3]));
+        assert_eq!(-(-p.clone()), p);
+    }
+
+    #[test]
+    fn test_new_trims_leading_zeros() {
+        let p = poly(&[1, 2, 0, 0]);
+        assert_eq!(p.degree, 1);
+        assert_eq!(p.coefficients.len(), 2);
+        assert!(poly(&[0, 0]).is_zero());
+        assert_eq!(poly(&[0]).evaluate(Fq::from(0)), Fq::from(0));
+    }
+}
@@ -0,0 +1,152 @@
+// ==============================================================//
+//    @note helpers for the `parallel` feature
+// =============================================================//
+
+// rayon or std iterators depending on the feature, used for the FFT butterflies, the product
+// coefficients and the subproduct tree layers
+
+macro_rules! cfg_iter {
+    ($e:expr) => {{
+        #[cfg(feature = "parallel")]
+        let result = $e.par_iter();
+        #[cfg(not(feature = "parallel"))]
+        let result = $e.iter();
+        result
+    }};
+}
+pub(crate) use cfg_iter;
+
+macro_rules! cfg_iter_mut {
+    ($e:expr) => {{
+        #[cfg(feature = "parallel")]
+        let result = $e.par_iter_mut();
+        #[cfg(not(feature = "parallel"))]
+        let result = $e.iter_mut();
+        result
+    }};
+}
+pub(crate) use cfg_iter_mut;
+
+macro_rules! cfg_into_iter {
+    ($e:expr) => {{
+        #[cfg(feature = "parallel")]
+        let result = $e.into_par_iter();
+        #[cfg(not(feature = "parallel"))]
+        let result = $e.into_iter();
+        result
+    }};
+}
+pub(crate) use cfg_into_iter;
+
+macro_rules! cfg_chunks {
+    ($e:expr, $size:expr) => {{
+        #[cfg(feature = "parallel")]
+        let result = $e.par_chunks($size);
+        #[cfg(not(feature = "parallel"))]
+        let result = $e.chunks($size);
+        result
+    }};
+}
+pub(crate) use cfg_chunks;
+
+macro_rules! cfg_chunks_mut {
+    ($e:expr, $size:expr) => {{
+        #[cfg(feature = "parallel")]
+        let result = $e.par_chunks_mut($size);
+        #[cfg(not(feature = "parallel"))]
+        let result = $e.chunks_mut($size);
+        result
+    }};
+}
+pub(crate) use cfg_chunks_mut;
+
+// runs both closures, in parallel when the feature is enabled
+pub(crate) fn join<A, B, RA, RB>(a: A, b: B) -> (RA, RB)
+where
+    A: FnOnce() -> RA + Send,
+    B: FnOnce() -> RB + Send,
+    RA: Send,
+    RB: Send,
+{
+    #[cfg(feature = "parallel")]
+    {
+        rayon::join(a, b)
+    }
+    #[cfg(not(feature = "parallel"))]
+    {
+        (a(), b())
+    }
+}
+
+#[cfg(all(test, feature = "parallel"))]
+mod tests {
+    use crate::dense_polynomial::UnivariatePolynomialDense;
+    use crate::domain::Radix2Domain;
+    use ark_bn254::Fr;
+    use ark_ff::UniformRand;
+    use rand::thread_rng;
+
+    fn random_values(n: usize) -> Vec<Fr> {
+        let mut rng = thread_rng();
+        (0..n).map(|_| Fr::rand(&mut rng)).collect()
+    }
+
+    fn serial_product(a: &[Fr], b: &[Fr]) -> Vec<Fr> {
+        let mut product = vec![Fr::from(0); a.len() + b.len() - 1];
+        for (i, a_i) in a.iter().enumerate() {
+            for (j, b_j) in b.iter().enumerate() {
+                product[i + j] += *a_i * b_j;
+            }
+        }
+        product
+    }
+
+    fn serial_evaluate(coefficients: &[Fr], x: Fr) -> Fr {
+        let mut value = Fr::from(0);
+        for coefficient in coefficients.iter().rev() {
+            value = value * x + coefficient;
+        }
+        value
+    }
+
+    #[test]
+    fn test_multiplication_matches_serial() {
+        let a = random_values(300);
+        let b = random_values(200);
+        let expected = serial_product(&a, &b);
+        let p = UnivariatePolynomialDense::new(a);
+        let q = UnivariatePolynomialDense::new(b);
+        assert_eq!(p.schoolbook_multiplication(&q).coefficients, expected);
+        assert_eq!(p.fft_multiplication(&q).unwrap().coefficients, expected);
+    }
+
+    #[test]
+    fn test_fft_matches_serial() {
+        let coefficients = random_values(256);
+        let domain = Radix2Domain::<Fr>::new(256).unwrap();
+        let expected: Vec<Fr> = (0..256)
+            .map(|i| serial_evaluate(&coefficients, domain.element(i)))
+            .collect();
+        assert_eq!(domain.fft(&coefficients), expected);
+        assert_eq!(domain.ifft(&expected), coefficients);
+    }
+
+    #[test]
+    fn test_multipoint_matches_serial() {
+        let coefficients = random_values(100);
+        let points = random_values(150);
+        let polynomial = UnivariatePolynomialDense::new(coefficients.clone());
+        let expected: Vec<Fr> = points
+            .iter()
+            .map(|x| serial_evaluate(&coefficients, *x))
+            .collect();
+        assert_eq!(polynomial.evaluate_many(&points), expected);
+
+        let values = random_values(150);
+        let interpolated = UnivariatePolynomialDense::interpolate_fast(&points, &values);
+        assert_eq!(
+            interpolated,
+            UnivariatePolynomialDense::interpolate(points, values)
+        );
+    }
+}
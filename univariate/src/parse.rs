@@ -0,0 +1,311 @@
+use crate::dense_polynomial::UnivariatePolynomialDense;
+use ark_ff::PrimeField;
+use std::fmt;
+use std::str::FromStr;
+
+// ==============================================================//
+//    @note human readable form: "3x^2 - 2x + 1"
+// =============================================================//
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum ParsePolynomialError {
+    // the input has no terms at all
+    Empty,
+    // a character that cannot appear at this position (0-based character index)
+    UnexpectedCharacter { position: usize, found: char },
+    // the input stopped in the middle of a term, e.g "3x^" or "2 +"
+    UnexpectedEnd,
+    // a coefficient that is not a valid field element
+    InvalidCoefficient(String),
+    // an exponent that does not fit in a usize or is above MAX_EXPONENT
+    InvalidExponent(String),
+}
+
+impl fmt::Display for ParsePolynomialError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            ParsePolynomialError::Empty => write!(f, "empty polynomial"),
+            ParsePolynomialError::UnexpectedCharacter { position, found } => {
+                write!(
+                    f,
+                    "unexpected character '{}' at position {}",
+                    found, position
+                )
+            }
+            ParsePolynomialError::UnexpectedEnd => write!(f, "unexpected end of input"),
+            ParsePolynomialError::InvalidCoefficient(coefficient) => {
+                write!(f, "invalid coefficient '{}'", coefficient)
+            }
+            ParsePolynomialError::InvalidExponent(exponent) => {
+                write!(f, "invalid exponent '{}'", exponent)
+            }
+        }
+    }
+}
+
+impl std::error::Error for ParsePolynomialError {}
+
+// the largest exponent accepted, the coefficients are stored densely so "x^100000000000" would
+// otherwise try to allocate every coefficient below it
+pub const MAX_EXPONENT: usize = 1 << 20;
+
+// elements above (p - 1) / 2 are printed as negatives, so -2 shows up as "- 2" instead of p - 2
+// returns whether the coefficient is negative and its absolute value
+fn split_sign<F: PrimeField>(coefficient: F) -> (bool, F) {
+    if coefficient.into_bigint() > F::MODULUS_MINUS_ONE_DIV_TWO {
+        (true, -coefficient)
+    } else {
+        (false, coefficient)
+    }
+}
+
+/// Prints the polynomial from the highest degree down, e.g `3x^2 - 2x + 1`. Unit coefficients
+/// are omitted and the zero polynomial prints as `0`.
+impl<F: PrimeField> fmt::Display for UnivariatePolynomialDense<F> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        if self.is_zero() {
+            return write!(f, "0");
+        }
+        let mut first = true;
+        for (exponent, coefficient) in self.coefficients.iter().enumerate().rev() {
+            if coefficient.is_zero() {
+                continue;
+            }
+            let (negative, magnitude) = split_sign(*coefficient);
+            match (first, negative) {
+                (true, true) => write!(f, "-")?,
+                (true, false) => {}
+                (false, true) => write!(f, " - ")?,
+                (false, false) => write!(f, " + ")?,
+            }
+            first = false;
+            if exponent == 0 || !magnitude.is_one() {
+                write!(f, "{}", magnitude.into_bigint())?;
+            }
+            match exponent {
+                0 => {}
+                1 => write!(f, "x")?,
+                _ => write!(f, "x^{}", exponent)?,
+            }
+        }
+        Ok(())
+    }
+}
+
+struct Parser {
+    chars: Vec<char>,
+    position: usize,
+}
+
+impl Parser {
+    fn skip_whitespace(&mut self) {
+        while self.peek().is_some_and(|c| c.is_whitespace()) {
+            self.position += 1;
+        }
+    }
+
+    fn peek(&self) -> Option<char> {
+        self.chars.get(self.position).copied()
+    }
+
+    fn unexpected(&self) -> ParsePolynomialError {
+        match self.peek() {
+            Some(found) => ParsePolynomialError::UnexpectedCharacter {
+                position: self.position,
+                found,
+            },
+            None => ParsePolynomialError::UnexpectedEnd,
+        }
+    }
+
+    fn digits(&mut self) -> String {
+        let start = self.position;
+        while self.peek().is_some_and(|c| c.is_ascii_digit()) {
+            self.position += 1;
+        }
+        self.chars[start..self.position].iter().collect()
+    }
+
+    // term := coefficient | [coefficient] ['*'] 'x' ['^' exponent]
+    fn term<F: PrimeField>(&mut self) -> Result<(usize, F), ParsePolynomialError> {
+        self.skip_whitespace();
+        let digits = self.digits();
+        let has_coefficient = !digits.is_empty();
+        let coefficient = if has_coefficient {
+            F::from_str(&digits).map_err(|_| ParsePolynomialError::InvalidCoefficient(digits))?
+        } else {
+            F::one()
+        };
+        self.skip_whitespace();
+        let has_star = self.peek() == Some('*');
+        if has_star {
+            self.position += 1;
+            self.skip_whitespace();
+        }
+        if self.peek() != Some('x') {
+            if !has_coefficient || has_star {
+                return Err(self.unexpected());
+            }
+            return Ok((0, coefficient));
+        }
+        self.position += 1;
+        self.skip_whitespace();
+        if self.peek() != Some('^') {
+            return Ok((1, coefficient));
+        }
+        self.position += 1;
+        self.skip_whitespace();
+        let exponent = self.digits();
+        if exponent.is_empty() {
+            return Err(self.unexpected());
+        }
+        match exponent.parse::<usize>() {
+            Ok(value) if value <= MAX_EXPONENT => Ok((value, coefficient)),
+            _ => Err(ParsePolynomialError::InvalidExponent(exponent)),
+        }
+    }
+}
+
+/// Parses sums of terms like `3x^2`, `x`, `2*x` and `7`, separated by `+` or `-`. Terms may
+/// appear in any order and repeated exponents are added together.
+impl<F: PrimeField> FromStr for UnivariatePolynomialDense<F> {
+    type Err = ParsePolynomialError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let mut parser = Parser {
+            chars: s.chars().collect(),
+            position: 0,
+        };
+        parser.skip_whitespace();
+        if parser.peek().is_none() {
+            return Err(ParsePolynomialError::Empty);
+        }
+        let mut coefficients: Vec<F> = Vec::new();
+        let mut negative = false;
+        if let Some(sign @ ('+' | '-')) = parser.peek() {
+            negative = sign == '-';
+            parser.position += 1;
+        }
+        loop {
+            let (exponent, coefficient) = parser.term::<F>()?;
+            if coefficients.len() <= exponent {
+                coefficients.resize(exponent + 1, F::zero());
+            }
+            if negative {
+                coefficients[exponent] -= coefficient;
+            } else {
+                coefficients[exponent] += coefficient;
+            }
+            parser.skip_whitespace();
+            match parser.peek() {
+                None => break,
+                Some(sign @ ('+' | '-')) => {
+                    negative = sign == '-';
+                    parser.position += 1;
+                }
+                Some(_) => return Err(parser.unexpected()),
+            }
+        }
+        Ok(UnivariatePolynomialDense::new(coefficients))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use ark_bn254::Fq;
+    use ark_ff::Field;
+
+    fn poly(values: &[i64]) -> UnivariatePolynomialDense<Fq> {
+        UnivariatePolynomialDense::new(values.iter().map(|value| Fq::from(*value)).collect())
+    }
+
+    #[test]
+    fn test_display() {
+        assert_eq!(poly(&[1, 2, 3]).to_string(), "3x^2 + 2x + 1");
+        assert_eq!(poly(&[-1, 0, 1]).to_string(), "x^2 - 1");
+        assert_eq!(poly(&[0, -1, -4]).to_string(), "-4x^2 - x");
+        assert_eq!(poly(&[1]).to_string(), "1");
+        assert_eq!(poly(&[0]).to_string(), "0");
+    }
+
+    #[test]
+    fn test_parse() {
+        assert_eq!("3x^2 + 2x + 1".parse(), Ok(poly(&[1, 2, 3])));
+        assert_eq!("1+2*x+3 * x ^ 2".parse(), Ok(poly(&[1, 2, 3])));
+        assert_eq!("-x + x^3 - 5".parse(), Ok(poly(&[-5, -1, 0, 1])));
+        assert_eq!("x + x - 2x".parse(), Ok(poly(&[0])));
+        assert_eq!("0".parse(), Ok(poly(&[])));
+    }
+
+    #[test]
+    fn test_round_trip() {
+        for values in [
+            vec![1, 2, 3],
+            vec![0, 0, 0, -7],
+            vec![-1, 1],
+            vec![5],
+            vec![],
+        ] {
+            let p = poly(&values);
+            assert_eq!(p.to_string().parse(), Ok(p));
+        }
+        let large =
+            UnivariatePolynomialDense::new(vec![Fq::from(3).inverse().unwrap(), Fq::from(2)]);
+        assert_eq!(large.to_string().parse(), Ok(large));
+    }
+
+    #[test]
+    fn test_parse_errors() {
+        type Result = std::result::Result<UnivariatePolynomialDense<Fq>, ParsePolynomialError>;
+        assert_eq!(
+            "".parse::<UnivariatePolynomialDense<Fq>>(),
+            Err(ParsePolynomialError::Empty)
+        );
+        assert_eq!(
+            "3x^2 + ".parse() as Result,
+            Err(ParsePolynomialError::UnexpectedEnd)
+        );
+        assert_eq!(
+            "3x^ + 1".parse() as Result,
+            Err(ParsePolynomialError::UnexpectedCharacter {
+                position: 4,
+                found: '+'
+            })
+        );
+        assert_eq!(
+            "2y".parse() as Result,
+            Err(ParsePolynomialError::UnexpectedCharacter {
+                position: 1,
+                found: 'y'
+            })
+        );
+        assert_eq!(
+            "2 3".parse() as Result,
+            Err(ParsePolynomialError::UnexpectedCharacter {
+                position: 2,
+                found: '3'
+            })
+        );
+        assert_eq!(
+            "x^99999999999999999999999".parse() as Result,
+            Err(ParsePolynomialError::InvalidExponent(
+                "99999999999999999999999".to_string()
+            ))
+        );
+        assert_eq!(
+            "x^100000000000".parse() as Result,
+            Err(ParsePolynomialError::InvalidExponent(
+                "100000000000".to_string()
+            ))
+        );
+        assert_eq!(
+            "1 + x^18446744073709551615".parse() as Result,
+            Err(ParsePolynomialError::InvalidExponent(
+                "18446744073709551615".to_string()
+            ))
+        );
+        let largest = format!("x^{}", MAX_EXPONENT).parse::<UnivariatePolynomialDense<Fq>>();
+        assert_eq!(largest.unwrap().degree, MAX_EXPONENT as u64);
+    }
+}
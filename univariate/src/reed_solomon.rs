@@ -0,0 +1,215 @@
+use crate::dense_polynomial::UnivariatePolynomialDense;
+use crate::domain::Radix2Domain;
+use crate::multipoint::SubproductTree;
+use ark_ff::PrimeField;
+use std::fmt;
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum DecodingError {
+    // the received word does not have one symbol per evaluation point
+    LengthMismatch,
+    // more symbols are corrupted than the code can correct
+    TooManyErrors,
+    // the message length is 0 or larger than the number of evaluation points
+    InvalidMessageLength,
+    // two evaluation points are equal
+    RepeatedEvaluationPoint,
+}
+
+impl fmt::Display for DecodingError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            DecodingError::LengthMismatch => {
+                write!(f, "received word length does not match the code length")
+            }
+            DecodingError::TooManyErrors => write!(f, "too many errors to decode"),
+            DecodingError::InvalidMessageLength => write!(
+                f,
+                "the message length must be between 1 and the number of evaluation points"
+            ),
+            DecodingError::RepeatedEvaluationPoint => {
+                write!(f, "evaluation points must be distinct")
+            }
+        }
+    }
+}
+
+impl std::error::Error for DecodingError {}
+
+/// A Reed–Solomon code: messages are polynomials of degree < `message_length`, codewords are
+/// their evaluations over `evaluation_points`.
+pub struct ReedSolomonCode<F: PrimeField> {
+    pub evaluation_points: Vec<F>,
+    pub message_length: usize,
+    // built once, its root is the vanishing polynomial of the evaluation points
+    tree: SubproductTree<F>,
+}
+
+impl<F: PrimeField> ReedSolomonCode<F> {
+    pub fn new(evaluation_points: Vec<F>, message_length: usize) -> Self {
+        ReedSolomonCode::try_new(evaluation_points, message_length)
+            .unwrap_or_else(|error| panic!("{}", error))
+    }
+
+    // the same as new, but returns an error instead of panicking on bad parameters
+    pub fn try_new(
+        evaluation_points: Vec<F>,
+        message_length: usize,
+    ) -> Result<Self, DecodingError> {
+        if message_length == 0 || message_length > evaluation_points.len() {
+            return Err(DecodingError::InvalidMessageLength);
+        }
+        let mut sorted = evaluation_points.clone();
+        sorted.sort();
+        sorted.dedup();
+        if sorted.len() != evaluation_points.len() {
+            return Err(DecodingError::RepeatedEvaluationPoint);
+        }
+        let tree = SubproductTree::new(&evaluation_points);
+        Ok(Self {
+            evaluation_points,
+            message_length,
+            tree,
+        })
+    }
+
+    // a code evaluated over a subgroup or coset, as used for FRI
+    pub fn from_domain(domain: &Radix2Domain<F>, message_length: usize) -> Self {
+        ReedSolomonCode::new(domain.elements(), message_length)
+    }
+
+    pub fn codeword_length(&self) -> usize {
+        self.evaluation_points.len()
+    }
+
+    // the code has minimum distance n - k + 1, so it corrects up to (n - k) / 2 errors
+    pub fn max_errors(&self) -> usize {
+        (self.codeword_length() - self.message_length) / 2
+    }
+
+    pub fn encode(&self, message: &UnivariatePolynomialDense<F>) -> Vec<F> {
+        if message.coefficients.len() > self.message_length {
+            panic!("message polynomial degree is too large for this code");
+        }
+        self.tree.evaluate(message)
+    }
+
+    // encodes the message given as coefficients, lowest degree first
+    pub fn encode_message(&self, message: &[F]) -> Vec<F> {
+        self.encode(&UnivariatePolynomialDense::new(message.to_vec()))
+    }
+
+    /// Gao's decoder: returns the message polynomial and the indices of the corrupted symbols.
+    ///
+    /// With g0 the vanishing polynomial and g1 the interpolant of the received word, the
+    /// extended Euclidean algorithm is stopped at the first remainder g of degree < (n + k) / 2,
+    /// giving g = u * g0 + v * g1. The message is g / v when at most (n - k) / 2 symbols are wrong.
+    pub fn decode(
+        &self,
+        received: &[F],
+    ) -> Result<(UnivariatePolynomialDense<F>, Vec<usize>), DecodingError> {
+        let n = self.codeword_length();
+        if received.len() != n {
+            return Err(DecodingError::LengthMismatch);
+        }
+        let interpolant = self.tree.interpolate(received);
+        let degree_bound = (n + self.message_length).div_ceil(2);
+        let (remainder, _, error_locator) =
+            self.tree.root().partial_xgcd(&interpolant, degree_bound);
+
+        let message = remainder
+            .divide_exact(&error_locator)
+            .map_err(|_| DecodingError::TooManyErrors)?;
+        if message.coefficients.len() > self.message_length {
+            return Err(DecodingError::TooManyErrors);
+        }
+        let error_positions: Vec<usize> = self
+            .encode(&message)
+            .iter()
+            .zip(received)
+            .enumerate()
+            .filter(|(_, (expected, actual))| expected != actual)
+            .map(|(i, _)| i)
+            .collect();
+        if error_positions.len() > self.max_errors() {
+            return Err(DecodingError::TooManyErrors);
+        }
+        Ok((message, error_positions))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use ark_bn254::{Fq, Fr};
+
+    fn code(n: u64, k: usize) -> ReedSolomonCode<Fq> {
+        ReedSolomonCode::new((1..=n).map(Fq::from).collect(), k)
+    }
+
+    #[test]
+    fn test_encode() {
+        let code = code(6, 3);
+        let message = UnivariatePolynomialDense::new(vec![Fq::from(1), Fq::from(2), Fq::from(3)]);
+        let codeword = code.encode(&message);
+        let expected: Vec<Fq> = (1..=6).map(|x| message.evaluate(Fq::from(x))).collect();
+        assert_eq!(codeword, expected);
+        assert_eq!(code.encode_message(&message.coefficients), expected);
+        assert_eq!(code.max_errors(), 1);
+    }
+
+    #[test]
+    fn test_decode_without_errors() {
+        let code = code(7, 3);
+        let message = UnivariatePolynomialDense::new(vec![Fq::from(4), Fq::from(0), Fq::from(9)]);
+        let (decoded, errors) = code.decode(&code.encode(&message)).unwrap();
+        assert_eq!(decoded, message);
+        assert!(errors.is_empty());
+    }
+
+    #[test]
+    fn test_decode_with_errors() {
+        let code = code(12, 4);
+        let message = UnivariatePolynomialDense::new(vec![
+            Fq::from(11),
+            Fq::from(7),
+            Fq::from(5),
+            Fq::from(3),
+        ]);
+        let mut received = code.encode(&message);
+        for i in [1, 5, 6, 10] {
+            received[i] += Fq::from(100 + i as u64);
+        }
+        let (decoded, errors) = code.decode(&received).unwrap();
+        assert_eq!(decoded, message);
+        assert_eq!(errors, vec![1, 5, 6, 10]);
+    }
+
+    #[test]
+    fn test_decode_too_many_errors() {
+        let code = code(8, 4);
+        let message = UnivariatePolynomialDense::new(vec![Fq::from(1), Fq::from(2)]);
+        let mut received = code.encode(&message);
+        for value in received.iter_mut().take(3) {
+            *value += Fq::from(1);
+        }
+        assert_eq!(code.decode(&received), Err(DecodingError::TooManyErrors));
+        assert_eq!(
+            code.decode(&received[1..]),
+            Err(DecodingError::LengthMismatch)
+        );
+    }
+
+    #[test]
+    fn test_decode_over_domain() {
+        let domain = Radix2Domain::<Fr>::new(16).unwrap();
+        let code = ReedSolomonCode::from_domain(&domain, 4);
+        let message = UnivariatePolynomialDense::new((1..=4).map(|i| Fr::from(i * 13)).collect());
+        let mut received = code.encode(&message);
+        received[0] = Fr::from(0);
+        received[15] = Fr::from(0);
+        let (decoded, errors) = code.decode(&received).unwrap();
+        assert_eq!(decoded, message);
+        assert_eq!(errors, vec![0, 15]);
+    }
+}
@@ -0,0 +1,125 @@
+use crate::dense_polynomial::UnivariatePolynomialDense;
+use ark_ff::PrimeField;
+use ark_serialize::{
+    CanonicalDeserialize, CanonicalSerialize, Compress, Read, SerializationError, Valid, Validate,
+    Write,
+};
+
+// ==============================================================//
+//    @note canonical (de)serialization
+// =============================================================//
+
+// a dense polynomial is written as its coefficient vector: a u64 length followed by the
+// coefficients from x^0 up, so the degree is implied by the length
+
+impl<F: PrimeField> CanonicalSerialize for UnivariatePolynomialDense<F> {
+    fn serialize_with_mode<W: Write>(
+        &self,
+        writer: W,
+        compress: Compress,
+    ) -> Result<(), SerializationError> {
+        self.coefficients.serialize_with_mode(writer, compress)
+    }
+
+    fn serialized_size(&self, compress: Compress) -> usize {
+        self.coefficients.serialized_size(compress)
+    }
+}
+
+// rejects zero leading coefficients, so every polynomial has exactly one encoding
+impl<F: PrimeField> Valid for UnivariatePolynomialDense<F> {
+    fn check(&self) -> Result<(), SerializationError> {
+        if self.coefficients.last().is_some_and(|c| c.is_zero()) {
+            return Err(SerializationError::InvalidData);
+        }
+        let expected_degree = self.coefficients.len().saturating_sub(1) as u64;
+        if self.degree != expected_degree {
+            return Err(SerializationError::InvalidData);
+        }
+        Ok(())
+    }
+}
+
+impl<F: PrimeField> CanonicalDeserialize for UnivariatePolynomialDense<F> {
+    fn deserialize_with_mode<R: Read>(
+        reader: R,
+        compress: Compress,
+        validate: Validate,
+    ) -> Result<Self, SerializationError> {
+        let coefficients = Vec::<F>::deserialize_with_mode(reader, compress, validate)?;
+        if validate == Validate::No {
+            // skipping the check still has to give a trimmed polynomial
+            return Ok(Self::new(coefficients));
+        }
+        let degree = coefficients.len().saturating_sub(1) as u64;
+        let polynomial = Self {
+            degree,
+            coefficients,
+        };
+        polynomial.check()?;
+        Ok(polynomial)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use ark_bn254::{Fq, Fr};
+
+    #[test]
+    fn test_round_trip() {
+        let poly = UnivariatePolynomialDense::new(vec![Fq::from(3), Fq::from(0), -Fq::from(7)]);
+        for compress in [Compress::Yes, Compress::No] {
+            let mut bytes = Vec::new();
+            poly.serialize_with_mode(&mut bytes, compress).unwrap();
+            assert_eq!(bytes.len(), poly.serialized_size(compress));
+            let decoded = UnivariatePolynomialDense::<Fq>::deserialize_with_mode(
+                &bytes[..],
+                compress,
+                Validate::Yes,
+            )
+            .unwrap();
+            assert_eq!(decoded, poly);
+        }
+
+        let zero = UnivariatePolynomialDense::<Fr>::new(Vec::new());
+        let mut bytes = Vec::new();
+        zero.serialize_compressed(&mut bytes).unwrap();
+        assert_eq!(
+            UnivariatePolynomialDense::<Fr>::deserialize_compressed(&bytes[..]).unwrap(),
+            zero
+        );
+    }
+
+    #[test]
+    fn test_rejects_leading_zero() {
+        let mut bytes = Vec::new();
+        vec![Fq::from(1), Fq::from(0)]
+            .serialize_compressed(&mut bytes)
+            .unwrap();
+        assert!(matches!(
+            UnivariatePolynomialDense::<Fq>::deserialize_compressed(&bytes[..]),
+            Err(SerializationError::InvalidData)
+        ));
+        // the unchecked path trims the leading zeros instead
+        let unchecked =
+            UnivariatePolynomialDense::<Fq>::deserialize_compressed_unchecked(&bytes[..]).unwrap();
+        assert_eq!(unchecked, UnivariatePolynomialDense::new(vec![Fq::from(1)]));
+        assert_eq!(unchecked.degree, 0);
+
+        let mut bytes = Vec::new();
+        vec![Fq::from(0)].serialize_compressed(&mut bytes).unwrap();
+        let zero =
+            UnivariatePolynomialDense::<Fq>::deserialize_compressed_unchecked(&bytes[..]).unwrap();
+        assert!(zero.is_zero());
+    }
+
+    #[test]
+    fn test_rejects_truncated_input() {
+        let poly = UnivariatePolynomialDense::new(vec![Fq::from(1), Fq::from(2)]);
+        let mut bytes = Vec::new();
+        poly.serialize_uncompressed(&mut bytes).unwrap();
+        bytes.pop();
+        assert!(UnivariatePolynomialDense::<Fq>::deserialize_uncompressed(&bytes[..]).is_err());
+    }
+}
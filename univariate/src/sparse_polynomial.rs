@@ -0,0 +1,356 @@
+use crate::dense_polynomial::UnivariatePolynomialDense;
+use crate::ops::forward_binary_op;
+use ark_ff::{PrimeField, Zero};
+use std::collections::BTreeMap;
+use std::ops::{Add, Mul, Neg, Sub};
+
+/// A struct representing a univariate polynomial in sparse form, i.e a map from exponent to a
+/// non-zero coefficient.
+///
+/// Useful for polynomials such as x^(2^20) - 1 where almost every coefficient is zero.
+/// Like the dense form, the zero polynomial has no terms and degree 0.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct UnivariatePolynomialSparse<F: PrimeField> {
+    pub degree: u64,
+    pub terms: BTreeMap<u64, F>,
+}
+
+type Dense<F> = UnivariatePolynomialDense<F>;
+type Sparse<F> = UnivariatePolynomialSparse<F>;
+
+impl<F: PrimeField> UnivariatePolynomialSparse<F> {
+    /// Creates a new `UnivariatePolynomialSparse` from a list of (exponent, coefficient) terms.
+    ///
+    /// Terms with the same exponent are added together and zero coefficients are dropped.
+    pub fn new(terms: Vec<(u64, F)>) -> Self {
+        let mut term_map: BTreeMap<u64, F> = BTreeMap::new();
+        for (exponent, coefficient) in terms {
+            *term_map.entry(exponent).or_insert(F::zero()) += coefficient;
+        }
+        Self::from_map(term_map)
+    }
+
+    // drops the zero coefficients and recomputes the degree
+    fn from_map(mut terms: BTreeMap<u64, F>) -> Self {
+        terms.retain(|_, coefficient| !coefficient.is_zero());
+        let degree = terms.keys().next_back().copied().unwrap_or(0);
+        Self { degree, terms }
+    }
+
+    // returns true for the zero polynomial
+    pub fn is_zero(&self) -> bool {
+        self.terms.is_empty()
+    }
+
+    // number of non-zero terms
+    pub fn num_terms(&self) -> usize {
+        self.terms.len()
+    }
+
+    // returns the coefficient of x^exponent
+    pub fn coefficient(&self, exponent: u64) -> F {
+        self.terms.get(&exponent).copied().unwrap_or(F::zero())
+    }
+
+    /// Evaluates the polynomial at `x` in O(t log d) for t terms of degree at most d.
+    ///
+    /// Terms are visited in increasing exponent order and each power is reached from the previous
+    /// one by repeated squaring on the exponent gap, so x^(2^20) costs ~20 multiplications.
+    pub fn evaluate(&self, x: F) -> F {
+        let mut val = F::zero();
+        let mut current_exponent = 0u64;
+        let mut current_power = F::one();
+        for (exponent, coefficient) in self.terms.iter() {
+            current_power *= x.pow([exponent - current_exponent]);
+            current_exponent = *exponent;
+            val += current_power * coefficient;
+        }
+        val
+    }
+
+    pub fn polynomial_addition(&self, other: &UnivariatePolynomialSparse<F>) -> Self {
+        let mut terms = self.terms.clone();
+        for (exponent, coefficient) in other.terms.iter() {
+            *terms.entry(*exponent).or_insert(F::zero()) += coefficient;
+        }
+        Self::from_map(terms)
+    }
+
+    pub fn polynomial_subtraction(&self, other: &UnivariatePolynomialSparse<F>) -> Self {
+        let mut terms = self.terms.clone();
+        for (exponent, coefficient) in other.terms.iter() {
+            *terms.entry(*exponent).or_insert(F::zero()) -= coefficient;
+        }
+        Self::from_map(terms)
+    }
+
+    // multiplies every pair of terms, O(t1 * t2)
+    pub fn polynomial_multiplication(&self, other: &UnivariatePolynomialSparse<F>) -> Self {
+        let mut terms: BTreeMap<u64, F> = BTreeMap::new();
+        for (exponent_a, coefficient_a) in self.terms.iter() {
+            for (exponent_b, coefficient_b) in other.terms.iter() {
+                *terms.entry(exponent_a + exponent_b).or_insert(F::zero()) +=
+                    *coefficient_a * coefficient_b;
+            }
+        }
+        Self::from_map(terms)
+    }
+
+    pub fn scalar_multiplication(&self, scalar: F) -> Self {
+        Self::from_map(
+            self.terms
+                .iter()
+                .map(|(exponent, coefficient)| (*exponent, *coefficient * scalar))
+                .collect(),
+        )
+    }
+
+    // converts to the dense form, allocating degree + 1 coefficients
+    pub fn to_dense(&self) -> UnivariatePolynomialDense<F> {
+        if self.is_zero() {
+            return UnivariatePolynomialDense::zero();
+        }
+        let mut coefficients = vec![F::zero(); self.degree as usize + 1];
+        for (exponent, coefficient) in self.terms.iter() {
+            coefficients[*exponent as usize] = *coefficient;
+        }
+        UnivariatePolynomialDense::new(coefficients)
+    }
+
+    // converts from the dense form, keeping only the non-zero coefficients
+    pub fn from_dense(dense: &UnivariatePolynomialDense<F>) -> Self {
+        Self::from_map(
+            dense
+                .coefficients
+                .iter()
+                .enumerate()
+                .map(|(exponent, coefficient)| (exponent as u64, *coefficient))
+                .collect(),
+        )
+    }
+}
+
+// ==============================================================//
+//    @note mixed sparse/dense arithmetic, the result is dense
+// =============================================================//
+
+fn add_sparse_dense<F: PrimeField>(sparse: &Sparse<F>, dense: &Dense<F>) -> Dense<F> {
+    let mut coefficients = dense.coefficients.clone();
+    let len = coefficients.len().max(sparse.degree as usize + 1);
+    coefficients.resize(len, F::zero());
+    for (exponent, coefficient) in sparse.terms.iter() {
+        coefficients[*exponent as usize] += coefficient;
+    }
+    UnivariatePolynomialDense::new(coefficients)
+}
+
+fn add_dense_sparse<F: PrimeField>(dense: &Dense<F>, sparse: &Sparse<F>) -> Dense<F> {
+    add_sparse_dense(sparse, dense)
+}
+
+fn sub_dense_sparse<F: PrimeField>(dense: &Dense<F>, sparse: &Sparse<F>) -> Dense<F> {
+    add_sparse_dense(&-sparse, dense)
+}
+
+fn sub_sparse_dense<F: PrimeField>(sparse: &Sparse<F>, dense: &Dense<F>) -> Dense<F> {
+    add_sparse_dense(sparse, &-dense)
+}
+
+// shifts and scales the dense polynomial once per sparse term, O(t * n)
+fn mul_sparse_dense<F: PrimeField>(sparse: &Sparse<F>, dense: &Dense<F>) -> Dense<F> {
+    if sparse.is_zero() || dense.is_zero() {
+        return UnivariatePolynomialDense::zero();
+    }
+    let mut coefficients = vec![F::zero(); sparse.degree as usize + dense.coefficients.len()];
+    for (exponent, coefficient) in sparse.terms.iter() {
+        for (i, dense_coefficient) in dense.coefficients.iter().enumerate() {
+            coefficients[*exponent as usize + i] += *coefficient * dense_coefficient;
+        }
+    }
+    UnivariatePolynomialDense::new(coefficients)
+}
+
+fn mul_dense_sparse<F: PrimeField>(dense: &Dense<F>, sparse: &Sparse<F>) -> Dense<F> {
+    mul_sparse_dense(sparse, dense)
+}
+
+forward_binary_op!(
+    Add,
+    add,
+    Sparse,
+    Sparse,
+    Sparse,
+    Sparse::polynomial_addition
+);
+forward_binary_op!(
+    Sub,
+    sub,
+    Sparse,
+    Sparse,
+    Sparse,
+    Sparse::polynomial_subtraction
+);
+forward_binary_op!(
+    Mul,
+    mul,
+    Sparse,
+    Sparse,
+    Sparse,
+    Sparse::polynomial_multiplication
+);
+forward_binary_op!(Add, add, Sparse, Dense, Dense, add_sparse_dense);
+forward_binary_op!(Add, add, Dense, Sparse, Dense, add_dense_sparse);
+forward_binary_op!(Sub, sub, Sparse, Dense, Dense, sub_sparse_dense);
+forward_binary_op!(Sub, sub, Dense, Sparse, Dense, sub_dense_sparse);
+forward_binary_op!(Mul, mul, Sparse, Dense, Dense, mul_sparse_dense);
+forward_binary_op!(Mul, mul, Dense, Sparse, Dense, mul_dense_sparse);
+
+impl<F: PrimeField> Neg for &UnivariatePolynomialSparse<F> {
+    type Output = UnivariatePolynomialSparse<F>;
+
+    fn neg(self) -> Self::Output {
+        self.scalar_multiplication(-F::one())
+    }
+}
+
+impl<F: PrimeField> Neg for UnivariatePolynomialSparse<F> {
+    type Output = UnivariatePolynomialSparse<F>;
+
+    fn neg(self) -> Self::Output {
+        -&self
+    }
+}
+
+impl<F: PrimeField> Mul<F> for &UnivariatePolynomialSparse<F> {
+    type Output = UnivariatePolynomialSparse<F>;
+
+    fn mul(self, scalar: F) -> Self::Output {
+        self.scalar_multiplication(scalar)
+    }
+}
+
+impl<F: PrimeField> Mul<F> for UnivariatePolynomialSparse<F> {
+    type Output = UnivariatePolynomialSparse<F>;
+
+    fn mul(self, scalar: F) -> Self::Output {
+        self.scalar_multiplication(scalar)
+    }
+}
+
+impl<F: PrimeField> Zero for UnivariatePolynomialSparse<F> {
+    fn zero() -> Self {
+        Self::from_map(BTreeMap::new())
+    }
+
+    fn is_zero(&self) -> bool {
+        self.terms.is_empty()
+    }
+}
+
+impl<F: PrimeField> From<&UnivariatePolynomialDense<F>> for UnivariatePolynomialSparse<F> {
+    fn from(dense: &UnivariatePolynomialDense<F>) -> Self {
+        Self::from_dense(dense)
+    }
+}
+
+impl<F: PrimeField> From<UnivariatePolynomialDense<F>> for UnivariatePolynomialSparse<F> {
+    fn from(dense: UnivariatePolynomialDense<F>) -> Self {
+        Self::from_dense(&dense)
+    }
+}
+
+impl<F: PrimeField> From<&UnivariatePolynomialSparse<F>> for UnivariatePolynomialDense<F> {
+    fn from(sparse: &UnivariatePolynomialSparse<F>) -> Self {
+        sparse.to_dense()
+    }
+}
+
+impl<F: PrimeField> From<UnivariatePolynomialSparse<F>> for UnivariatePolynomialDense<F> {
+    fn from(sparse: UnivariatePolynomialSparse<F>) -> Self {
+        sparse.to_dense()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use ark_bn254::Fq;
+
+    fn to_fq(values: &[i64]) -> Vec<Fq> {
+        values.iter().map(|value| Fq::from(*value)).collect()
+    }
+
+    fn sparse(terms: &[(u64, i64)]) -> UnivariatePolynomialSparse<Fq> {
+        UnivariatePolynomialSparse::new(
+            terms
+                .iter()
+                .map(|(exponent, coefficient)| (*exponent, Fq::from(*coefficient)))
+                .collect(),
+        )
+    }
+
+    // x^(2^20) - 1
+    fn vanishing_poly() -> UnivariatePolynomialSparse<Fq> {
+        sparse(&[(1 << 20, 1), (0, -1)])
+    }
+
+    #[test]
+    fn test_create_sparse_polynomial() {
+        let poly = sparse(&[(3, 2), (0, 1), (3, 4), (7, 0)]);
+        assert_eq!(poly.degree, 3);
+        assert_eq!(poly.num_terms(), 2);
+        assert_eq!(poly.coefficient(3), Fq::from(6));
+        assert_eq!(poly.coefficient(7), Fq::from(0));
+        assert!(sparse(&[(5, 1), (5, -1)]).is_zero());
+    }
+
+    #[test]
+    fn test_evaluate() {
+        let poly = sparse(&[(0, 1), (2, 3), (5, 2)]);
+        // 1 + 3 * 4 + 2 * 32
+        assert_eq!(poly.evaluate(Fq::from(2)), Fq::from(77));
+        assert_eq!(
+            poly.evaluate(Fq::from(2)),
+            poly.to_dense().evaluate(Fq::from(2))
+        );
+
+        let vanishing = vanishing_poly();
+        assert_eq!(vanishing.evaluate(Fq::from(1)), Fq::from(0));
+        assert_eq!(vanishing.evaluate(-Fq::from(1)), Fq::from(0));
+    }
+
+    #[test]
+    fn test_sparse_arithmetic() {
+        let p = sparse(&[(0, 1), (10, 2)]);
+        let q = sparse(&[(10, -2), (20, 1)]);
+        assert_eq!(&p + &q, sparse(&[(0, 1), (20, 1)]));
+        assert_eq!(&p - &q, sparse(&[(0, 1), (10, 4), (20, -1)]));
+        assert_eq!(&p * &q, sparse(&[(10, -2), (20, 1), (20, -4), (30, 2)]));
+        assert_eq!(-&p, sparse(&[(0, -1), (10, -2)]));
+        assert_eq!(&p * Fq::from(3), sparse(&[(0, 3), (10, 6)]));
+        assert_eq!((&p - &p).degree, 0);
+    }
+
+    #[test]
+    fn test_mixed_arithmetic() {
+        let s = sparse(&[(0, -1), (4, 1)]);
+        let d = UnivariatePolynomialDense::new(to_fq(&[1, 2, 3]));
+        assert_eq!((&s + &d).coefficients, to_fq(&[0, 2, 3, 0, 1]));
+        assert_eq!(&d + &s, &s + &d);
+        assert_eq!((&d - &s).coefficients, to_fq(&[2, 2, 3, 0, -1]));
+        assert_eq!((&s - &d).coefficients, to_fq(&[-2, -2, -3, 0, 1]));
+        assert_eq!(&s * &d, s.to_dense().polynomial_multiplication(&d));
+        assert_eq!(&d * &s, &s * &d);
+    }
+
+    #[test]
+    fn test_conversions_roundtrip() {
+        let dense = UnivariatePolynomialDense::new(to_fq(&[0, 5, 0, 0, 7]));
+        let sparse_poly = UnivariatePolynomialSparse::from(&dense);
+        assert_eq!(sparse_poly, sparse(&[(1, 5), (4, 7)]));
+        assert_eq!(UnivariatePolynomialDense::from(&sparse_poly), dense);
+
+        let zero = UnivariatePolynomialSparse::<Fq>::zero();
+        assert!(UnivariatePolynomialDense::from(zero).is_zero());
+    }
+}